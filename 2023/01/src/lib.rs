@@ -0,0 +1,104 @@
+/*
+-lines of text
+    -value found by combining the first and last digit from line (In that order)
+    -both values are a single digit (1 char)
+    -value is a 2 digit number
+*/
+
+use aoc_common::{ParseResult, Solution};
+
+fn get_value_2(input: &str) -> i32 {
+    let conversion_table = [
+        ("1", "one", "1"),
+        ("2", "two", "2"),
+        ("3", "three", "3"),
+        ("4", "four", "4"),
+        ("5", "five", "5"),
+        ("6", "six", "6"),
+        ("7", "seven", "7"),
+        ("8", "eight", "8"),
+        ("9", "nine", "9"),
+    ];
+
+    let mut first: Option<String> = None;
+    let mut last: Option<String> = None;
+
+    for i in 0..input.len() {
+        let current_string: String = input.chars().skip(i).collect();
+
+        for (match1, match2, conversion_num) in conversion_table {
+            if current_string.starts_with(match1) || current_string.starts_with(match2) {
+                first = Some(conversion_num.to_owned());
+                break;
+            }
+        }
+        if first.is_some() {
+            break;
+        }
+    }
+
+    for i in (1..=input.len()).rev() {
+        let current_string: String = input.chars().take(i).collect();
+
+        for (match1, match2, conversion_num) in conversion_table {
+            if current_string.ends_with(match1) || current_string.ends_with(match2) {
+                last = Some(conversion_num.to_owned());
+                break;
+            }
+        }
+
+        if last.is_some() {
+            break;
+        }
+    }
+
+    let combined = match (first, last) {
+        (Some(s1), Some(s2)) => s1 + &s2,
+        (Some(s), None) | (None, Some(s)) => s,
+        (None, None) => return 0, // no digits, so no calibration value
+    };
+
+    combined.parse::<i32>().unwrap()
+}
+
+fn get_value_1(input: &str) -> i32 {
+    let mut first: Option<String> = None;
+    let mut last: Option<String> = None;
+
+    for c in input.chars() {
+        if c.to_string().parse::<i32>().is_ok() {
+            if first.is_none() {
+                first = Some(c.to_string())
+            }
+            last = Some(c.to_string())
+        }
+    }
+
+    let combined = match (first, last) {
+        (Some(s1), Some(s2)) => s1 + &s2,
+        (Some(s), None) | (None, Some(s)) => s,
+        (None, None) => return 0, // no digits, so no calibration value
+    };
+
+    combined.parse::<i32>().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        input.iter().map(|line| get_value_1(line)).sum()
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        input.iter().map(|line| get_value_2(line)).sum()
+    }
+}
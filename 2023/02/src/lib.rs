@@ -0,0 +1,140 @@
+/*
+cubes that can be red, green or blue
+n number of games from 1 to n
+each game we have m number of rounds, where we pull cubes out of the bag
+part 1:
+    - the cubers are put back into the bag so we check max number of cubes each round in the game for each color
+    - figure out if the game is possible with the set number of cubes
+    - get all the possible game nums
+    - sum up all the possible game numbers to get the correct answer
+
+part 2:
+    - the cubers are put back into the bag so we check max number of cubes each round in the game for each color
+    - figure out if the lest number of each color that the game is possible with
+    - multiply the cubes together for each game
+    - return the sum
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    number: u32,
+    num_cubes: HashMap<Cubes, u32>,
+}
+
+impl Game {
+    fn new(game_num: u32) -> Self {
+        let mut cubes = HashMap::new();
+
+        cubes.insert(Cubes::Red, 0);
+        cubes.insert(Cubes::Green, 0);
+        cubes.insert(Cubes::Blue, 0);
+
+        Game {
+            number: game_num,
+            num_cubes: cubes,
+        }
+    }
+
+    fn from_game_string(line: usize, whole_game: &str) -> ParseResult<Self> {
+        let (game, rounds_str) = parse::split_once(line, whole_game, whole_game, ":")?;
+        let (_, game_num_str) = parse::split_once(line, whole_game, game, " ")?;
+        let game_num = parse::number(line, whole_game, game_num_str)?;
+        let rounds: Vec<&str> = rounds_str.split(";").collect();
+
+        let mut curent_game = Game::new(game_num);
+
+        for round in rounds {
+            let cubes: Vec<&str> = round.split(",").collect();
+            for cube_str in cubes {
+                let (cube_num_str, cube_color) =
+                    parse::split_once(line, whole_game, cube_str.trim(), " ")?;
+
+                let cube_num = parse::number(line, whole_game, cube_num_str)?;
+                let cube_type = match cube_color {
+                    "red" => Cubes::Red,
+                    "green" => Cubes::Green,
+                    "blue" => Cubes::Blue,
+                    _ => {
+                        return Err(ParseError::at(
+                            line,
+                            whole_game,
+                            cube_color,
+                            "red, green or blue",
+                        ))
+                    }
+                };
+
+                if curent_game.num_cubes[&cube_type] < cube_num {
+                    curent_game.num_cubes.insert(cube_type, cube_num);
+                }
+            }
+        }
+
+        Ok(curent_game)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+enum Cubes {
+    Red,
+    Green,
+    Blue,
+}
+
+fn valid_games_sum_1(all_games: &[Game]) -> u32 {
+    let mut max_possible_cubes = HashMap::new();
+    max_possible_cubes.insert(Cubes::Red, 12);
+    max_possible_cubes.insert(Cubes::Green, 13);
+    max_possible_cubes.insert(Cubes::Blue, 14);
+
+    let possible_games: Vec<&Game> = all_games
+        .iter()
+        .filter(|game| {
+            let game_cubes = &game.num_cubes;
+            game_cubes[&Cubes::Red] <= max_possible_cubes[&Cubes::Red]
+                && game_cubes[&Cubes::Green] <= max_possible_cubes[&Cubes::Green]
+                && game_cubes[&Cubes::Blue] <= max_possible_cubes[&Cubes::Blue]
+        })
+        .collect();
+
+    possible_games.iter().map(|game| game.number).sum()
+}
+
+fn multiplied_cube_sum(all_games: &[Game]) -> u32 {
+    all_games
+        .iter()
+        .map(|game| {
+            let game_cubes = &game.num_cubes;
+            game_cubes[&Cubes::Red] * game_cubes[&Cubes::Green] * game_cubes[&Cubes::Blue]
+        })
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(line, whole_game)| Game::from_game_string(line, whole_game))
+            .collect()
+    }
+
+    fn part_1(all_games: &Self::Input) -> u32 {
+        valid_games_sum_1(all_games)
+    }
+
+    fn part_2(all_games: &Self::Input) -> u32 {
+        multiplied_cube_sum(all_games)
+    }
+}
@@ -0,0 +1,116 @@
+/*
+- scratch cards
+- card [numbers are winning numbers] | [my numbers]
+part 1:
+    - first match gives card 1 point and each other match doubles the point value
+part 2:
+    - now we win scratchcards equal to the number of winning matches we have
+    - if card 10 has 5 winning matches, I get 1 card of all of the next cards, so cards 11 - 15
+    - the result is the sum of the count of scratch cards
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    number: u32,
+    winning_nums: HashSet<u32>,
+    my_nums: HashSet<u32>,
+}
+
+impl Card {
+    fn new_from_string(line: usize, card_str: &str) -> ParseResult<Self> {
+        let (card_num_str, all_numbers_str) = parse::split_once(line, card_str, card_str, ":")?;
+        let (_, number_str) = parse::split_once(line, card_str, card_num_str, " ")?;
+        let number = parse::number(line, card_str, number_str.trim())?;
+        let (winning_nums_str, my_nums_str) =
+            parse::split_once(line, card_str, all_numbers_str, "|")?;
+        let winning_nums: Vec<u32> = parse::numbers(line, card_str, winning_nums_str)?;
+        let my_nums: Vec<u32> = parse::numbers(line, card_str, my_nums_str)?;
+
+        Ok(Card {
+            number,
+            winning_nums: HashSet::from_iter(winning_nums.iter().cloned()),
+            my_nums: HashSet::from_iter(my_nums.iter().cloned()),
+        })
+    }
+}
+
+fn get_card_matches(card: &Card) -> u32 {
+    let winning_nums: Vec<&u32> = card.my_nums.intersection(&card.winning_nums).collect();
+    winning_nums.len() as u32
+}
+
+fn get_card_points_1(all_cards: &[Card]) -> u32 {
+    let mut total_points = 0;
+    for card in all_cards {
+        let win_count = get_card_matches(card);
+        let new_points = match win_count {
+            0 => 0,
+            _ => u32::pow(2, win_count - 1),
+        };
+        total_points += new_points;
+    }
+
+    total_points
+}
+
+fn get_total_card_count_2(cards: &[Card]) -> u32 {
+    let mut all_cards: HashMap<u32, &Card> = HashMap::new();
+    for card in cards {
+        all_cards.insert(card.number, card);
+    }
+
+    let mut all_cards_and_winnings: Vec<&Card> = all_cards.values().copied().collect();
+
+    let mut index = 0;
+    loop {
+        let current_card = all_cards_and_winnings.get(index);
+        match current_card {
+            Some(card) => {
+                let win_wount = get_card_matches(card);
+
+                let won_cards_nums: Vec<u32> =
+                    ((card.number + 1)..=(card.number + win_wount)).collect();
+
+                let won_cards: Vec<&Card> = won_cards_nums
+                    .iter()
+                    .filter_map(|i| all_cards.get(i).copied())
+                    .collect();
+
+                all_cards_and_winnings.extend(won_cards);
+            }
+            None => break,
+        }
+        index += 1;
+    }
+
+    all_cards_and_winnings.len() as u32
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(line, card_str)| Card::new_from_string(line, card_str))
+            .collect()
+    }
+
+    fn part_1(all_cards: &Self::Input) -> u32 {
+        get_card_points_1(all_cards)
+    }
+
+    fn part_2(all_cards: &Self::Input) -> u32 {
+        get_total_card_count_2(all_cards)
+    }
+}
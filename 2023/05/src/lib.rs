@@ -0,0 +1,163 @@
+/*
+- the input is a list of seeds
+- we have maps that map the seed num to other values
+- the map are [destination range start] [source range start] [range length]
+- the seeds are mapped from seed -> soil -> fertilizer -> water -> light -> temperature -> huminidty -> location
+- if the input doesnt have a map value, the ending value is the same as the input value
+- the result is the min location value from the seeds
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, RangeSet, Solution};
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct RangeMapping {
+    dest_start: u32,
+    source_start: u32,
+    range_len: u32,
+}
+
+impl RangeMapping {
+    fn from_string(line: usize, input_str: &str) -> ParseResult<Self> {
+        let nums: Vec<u32> = parse::numbers(line, input_str, input_str)?;
+
+        let [dest_start, source_start, range_len] = nums[..] else {
+            return Err(ParseError::line(line, input_str, "three numbers"));
+        };
+
+        Ok(RangeMapping {
+            dest_start,
+            source_start,
+            range_len,
+        })
+    }
+
+    /// The source range with the start it is moved to
+    fn piece(&self) -> (Range<u64>, u64) {
+        let source_start = self.source_start as u64;
+        let source = source_start..source_start + self.range_len as u64;
+        (source, self.dest_start as u64)
+    }
+
+    fn get_next(&self, value: &u32) -> Option<u32> {
+        let diff: i32 = (*value as i32) - (self.source_start as i32);
+        if (diff >= 0) && (diff < self.range_len as i32) {
+            let new_value = self.dest_start + diff as u32;
+            return Some(new_value);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct AllRanges {
+    ranges: Vec<RangeMapping>,
+}
+
+impl AllRanges {
+    fn get_next_all(&self, value: &u32) -> Option<u32> {
+        self.ranges
+            .iter()
+            .map(|range_mapping| range_mapping.get_next(value))
+            .find(|value| value.is_some())
+            .flatten()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    seeds: Vec<u32>,
+    mapper: Vec<AllRanges>,
+}
+
+fn seed_to_location_mapping_1(almanac: &Almanac) -> u32 {
+    let mut final_locations = vec![];
+    for &seed in &almanac.seeds {
+        let seed_location = get_final_seed_location(&almanac.mapper, seed);
+        final_locations.push(seed_location);
+    }
+
+    *final_locations.iter().min().to_owned().unwrap()
+}
+
+// the seeds are ranges now, so whole ranges are moved through the maps at once instead of every seed
+fn seed_to_location_mapping_2(almanac: &Almanac) -> u32 {
+    let mut seeds: RangeSet<u64> = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| pair[0] as u64..pair[0] as u64 + pair[1] as u64)
+        .collect();
+
+    for map in &almanac.mapper {
+        seeds = seeds.map_ranges(map.ranges.iter().map(RangeMapping::piece));
+    }
+
+    // every seed range can be empty, then there is no location at all
+    seeds.min().map_or(0, |location| location as u32)
+}
+
+fn get_final_seed_location(mapper: &Vec<AllRanges>, seed: u32) -> u32 {
+    let mut current_value = seed;
+    for map in mapper {
+        let next_value = map.get_next_all(&current_value);
+        if let Some(value) = next_value {
+            current_value = value;
+        }
+    }
+    current_value
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Almanac> {
+    let seeds_str = parse::line(input, 0)?;
+    let (_, seed_values) = parse::split_once(0, seeds_str, seeds_str, ": ")?;
+    let seeds: Vec<u32> = parse::numbers(0, seeds_str, seed_values)?;
+    // part 2 reads the seeds as pairs of a start and a length
+    if seeds.is_empty() || !seeds.len().is_multiple_of(2) {
+        return Err(ParseError::at(
+            0,
+            seeds_str,
+            seed_values,
+            "pairs of seed numbers",
+        ));
+    }
+
+    let mut mapper: Vec<AllRanges> = vec![];
+    for (i, line) in input.iter().enumerate().skip(1) {
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.ends_with("map:") {
+            mapper.push(AllRanges { ranges: vec![] });
+            continue;
+        }
+
+        let current_map = mapper
+            .last_mut()
+            .ok_or_else(|| ParseError::line(i, line, "a map header"))?;
+        current_map.ranges.push(RangeMapping::from_string(i, line)?);
+    }
+
+    Ok(Almanac { seeds, mapper })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(almanac: &Self::Input) -> u32 {
+        seed_to_location_mapping_1(almanac)
+    }
+
+    fn part_2(almanac: &Self::Input) -> u32 {
+        seed_to_location_mapping_2(almanac)
+    }
+}
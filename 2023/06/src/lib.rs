@@ -0,0 +1,167 @@
+/*
+we have a boat race that we need to win
+the boat races are based on how much distance you can cover in a certain time
+the distance covered is determined by how long you charge the boat, but the longer you charge, the less time you have to go forward
+get the possible count of winning combinations of charing for every race
+multiply the winning combinations for all races together
+
+part 2:
+    it's all just a single race and you have to combine the time and distance letters
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct TimeDistance {
+    time: u64,
+    distance: u64,
+}
+
+impl TimeDistance {
+    fn calculate_num_winning(&self) -> u64 {
+        // x = total time
+        // m = time taken to charge
+
+        // (m) M/s * (x - m) S = M)
+        // (m) * (x - m) = Distance)
+        // -m^2 * xm = Distance
+        // -m^2 * xm - Distance = 0
+
+        let Some((min_charge_time, max_charge_time)) =
+            TimeDistance::solve_quadratic(-1, self.time as i64, -(self.distance as i64))
+        else {
+            // no charge time gets far enough
+            return 0;
+        };
+
+        (max_charge_time - min_charge_time).saturating_sub(1)
+    }
+
+    fn solve_quadratic(a: i64, b: i64, c: i64) -> Option<(u64, u64)> {
+        let discriminant: f64 = (b * b - 4 * a * c) as f64;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let min_time = (-b as f64 + discriminant.sqrt()) / (2.0 * a as f64);
+        let max_time = (-b as f64 - discriminant.sqrt()) / (2.0 * a as f64);
+
+        Some((min_time as u64, max_time.ceil() as u64))
+    }
+
+    fn solve_part_2(&self) -> u64 {
+        let mut min_time: u64 = 0;
+        let mut max_time: u64 = 0;
+
+        for t in 0..self.time {
+            if t * (self.time - t) > self.distance {
+                min_time = t;
+                break;
+            }
+        }
+
+        // charging for 0 never wins, so nothing did
+        if min_time == 0 {
+            return 0;
+        }
+
+        for t in (0..self.time).rev() {
+            if t * (self.time - t) > self.distance {
+                max_time = t;
+                break;
+            }
+        }
+
+        max_time - min_time + 1
+    }
+}
+
+fn get_winning_times_1(all_time_distances: &[TimeDistance]) -> u64 {
+    let mut winning_times_count: Vec<u64> = vec![];
+
+    for td in all_time_distances {
+        winning_times_count.push(td.calculate_num_winning());
+    }
+
+    winning_times_count.iter().product::<u64>()
+}
+
+fn get_winning_times_2(all_time_distances: &[TimeDistance]) -> u64 {
+    let mut winning_times_count: Vec<u64> = vec![];
+
+    for td in all_time_distances {
+        winning_times_count.push(td.solve_part_2());
+    }
+
+    winning_times_count[0]
+}
+
+fn parse_input_1(input: &[String]) -> ParseResult<Vec<TimeDistance>> {
+    let times_line = parse::line(input, 0)?;
+    let distance_line = parse::line(input, 1)?;
+    let (_, times_str) = parse::split_once(0, times_line, times_line, ":")?;
+    let (_, distance_str) = parse::split_once(1, distance_line, distance_line, ":")?;
+
+    let times: Vec<u64> = parse::numbers(0, times_line, times_str)?;
+    let distances: Vec<u64> = parse::numbers(1, distance_line, distance_str)?;
+    if distances.len() != times.len() {
+        return Err(ParseError::at(
+            1,
+            distance_line,
+            distance_str.trim(),
+            format!("{} distances, one for every time", times.len()),
+        ));
+    }
+
+    let mut all_times = vec![];
+    for (time, distance) in times.iter().zip(distances) {
+        let new_td = TimeDistance {
+            time: *time,
+            distance,
+        };
+
+        all_times.push(new_td);
+    }
+
+    Ok(all_times)
+}
+
+fn parse_input_2(input: &[String]) -> ParseResult<Vec<TimeDistance>> {
+    let times_line = parse::line(input, 0)?;
+    let distance_line = parse::line(input, 1)?;
+    let (_, times_str) = parse::split_once(0, times_line, times_line, ":")?;
+    let (_, distance_str) = parse::split_once(1, distance_line, distance_line, ":")?;
+
+    // the kerning is bad, so every number on the line is one big number
+    let time = times_str
+        .replace(" ", "")
+        .parse::<u64>()
+        .map_err(|_| ParseError::at(0, times_line, times_str.trim(), "a number"))?;
+    let distance = distance_str
+        .replace(" ", "")
+        .parse::<u64>()
+        .map_err(|_| ParseError::at(1, distance_line, distance_str.trim(), "a number"))?;
+
+    Ok(vec![TimeDistance { time, distance }])
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<TimeDistance>, Vec<TimeDistance>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((parse_input_1(input)?, parse_input_2(input)?))
+    }
+
+    fn part_1((races, _): &Self::Input) -> u64 {
+        get_winning_times_1(races)
+    }
+
+    fn part_2((_, single_race): &Self::Input) -> u64 {
+        get_winning_times_2(single_race)
+    }
+}
@@ -0,0 +1,139 @@
+/*
+
+We have a map of locations in a key - value map sort of way
+each key place has the possibility of going left of right
+the left and right options are another key values
+we start at AAA and we have to go through this map in a predefinied pattern of left and rights until we get to ZZZ
+the answer is the amount of steps that we had to take to get there
+
+part 2:
+    I have to start on all nodes that end with "A" and I have to move them all simultaniously until every single on of the nodes
+    is a place that ends with "Z" all the same time
+    and then i return the steps that I took
+*/
+
+use aoc_common::{math, parse};
+use aoc_common::{Graph, ParseError, ParseResult, Solution};
+use regex::Regex;
+
+// every location has the left and then the right location as its neighbours, both are kept when
+// they are the same location
+type LocationMap = Graph<String>;
+
+// the steps from `start` until a location `is_end`, `None` when the walk never gets there: once it
+// has been at every location at every point of the path it can only go around in circles
+fn steps_until(
+    path_vec: &[usize],
+    location_map: &LocationMap,
+    start: usize,
+    is_end: impl Fn(&str) -> bool,
+) -> Option<u64> {
+    let mut location = start;
+    let limit = path_vec.len() * location_map.len();
+
+    for (steps, turn) in path_vec.iter().cycle().take(limit).enumerate() {
+        location = location_map.neighbours(location)[*turn];
+        if is_end(location_map.name(location)) {
+            return Some(steps as u64 + 1);
+        }
+    }
+
+    None
+}
+
+fn get_shortest_path_1(path_vec: &[usize], location_map: &LocationMap) -> u32 {
+    // the part 2 example has no AAA to start from
+    let Some(start) = location_map.id("AAA") else {
+        return 0;
+    };
+
+    steps_until(path_vec, location_map, start, |name| name == "ZZZ").unwrap_or(0) as u32
+}
+
+fn get_shortest_path_2(path_vec: &[usize], location_map: &LocationMap) -> u64 {
+    let cycle_lens: Option<Vec<u64>> = location_map
+        .nodes()
+        .filter(|&id| location_map.name(id).ends_with("A"))
+        .map(|start| steps_until(path_vec, location_map, start, |name| name.ends_with("Z")))
+        .collect();
+    let Some(cycle_lens) = cycle_lens else {
+        return 0;
+    };
+
+    // least common multiple to find when the cycles intersect
+    math::lcm_all(cycle_lens)
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(Vec<usize>, LocationMap)> {
+    let path_str = parse::line(input, 0)?;
+
+    let mut path_vec: Vec<usize> = vec![];
+    for (column, c) in path_str.char_indices() {
+        match c {
+            'L' => path_vec.push(0),
+            'R' => path_vec.push(1),
+            _ => return Err(parse::unexpected_char(0, column, c, "'L' or 'R'")),
+        }
+    }
+
+    // AAA = (BBB, BBB)
+    let mut location_map: LocationMap = Graph::new();
+    let mut references = vec![];
+    let re = Regex::new(r"([A-Z\d]{3}) = \(([A-Z\d]{3})\, ([A-Z\d]{3})\)").unwrap();
+
+    for (line, loc) in input.iter().enumerate().skip(2) {
+        let caputers = re
+            .captures(loc)
+            .ok_or_else(|| ParseError::line(line, loc, "\"AAA = (BBB, CCC)\""))?;
+        let from = caputers.get(1).unwrap().as_str();
+        let id = location_map.add_node(from.to_owned());
+        if !location_map.neighbours(id).is_empty() {
+            return Err(ParseError::at(
+                line,
+                loc,
+                from,
+                "a location that is not listed yet",
+            ));
+        }
+
+        for next in [caputers.get(2).unwrap(), caputers.get(3).unwrap()] {
+            location_map.push_edge(from.to_owned(), next.as_str().to_owned());
+            references.push((line, next.as_str()));
+        }
+    }
+
+    // a location that only shows up on the right has nowhere to go
+    for (line, next) in references {
+        let id = location_map.id(next).unwrap();
+        if location_map.neighbours(id).is_empty() {
+            return Err(ParseError::at(
+                line,
+                &input[line],
+                next,
+                "a location with its own line",
+            ));
+        }
+    }
+
+    Ok((path_vec, location_map))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, LocationMap);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((path_vec, location_map): &Self::Input) -> u32 {
+        get_shortest_path_1(path_vec, location_map)
+    }
+
+    fn part_2((path_vec, location_map): &Self::Input) -> u64 {
+        get_shortest_path_2(path_vec, location_map)
+    }
+}
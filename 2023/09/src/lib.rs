@@ -0,0 +1,162 @@
+/*
+we have readings of sand instability from a reader:
+0 3 6 9 12 15
+we have to predict the next value by first disecting it to be the difference of previous value
+and do this until we have a row of 0s
+0   3   6   9  12  15
+  3   3   3   3   3
+    0   0   0   0
+
+and then we get the next values by adding up the lower list value and the list last value
+add 0 to the last list
+0 + 3 = 3
+3 + 15 = 18
+
+0   3   6   9  12  15  18
+  3   3   3   3   3   3
+    0   0   0   0   0
+
+so the next number is 18
+we do this to all lists and return the sum of the next values
+
+part 2:
+extrapolate backwards, so instead of trying to get a new value, we instead get the first value that should have become before the first value
+and we get the sum of the predicted previous values
+
+0   3   6   9  12  15
+  3   3   3   3   3
+    0   0   0   0
+
+becomes
+
+-3   0   3   6   9  12  15
+   3   3   3   3   3   3
+     0    0   0   0   0
+
+and the value we want is -3
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Readings {
+    nums: Vec<i32>,
+}
+
+impl Readings {
+    fn get_vec_diff(prev_vec: &[i32]) -> Vec<i32> {
+        prev_vec
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect()
+    }
+
+    fn get_prediction(&self) -> i32 {
+        let mut all_vecs: Vec<Vec<i32>> = vec![self.nums.clone()];
+
+        let mut done = false;
+        loop {
+            let new_diff = Readings::get_vec_diff(all_vecs.last().unwrap());
+
+            if new_diff.iter().all(|&x| x == 0) {
+                done = true;
+            }
+
+            all_vecs.push(new_diff);
+            if done {
+                break;
+            }
+        }
+
+        let mut sum = 0;
+        let mut new_values = vec![];
+        for vec in all_vecs.iter().rev() {
+            // a row that never settled to 0s ends in an empty one, which adds nothing
+            sum += vec.last().unwrap_or(&0);
+            new_values.push(sum);
+        }
+
+        sum
+    }
+
+    fn get_prev_value(&self) -> i32 {
+        let mut all_vecs: Vec<Vec<i32>> = vec![self.nums.clone()];
+
+        let mut done = false;
+        loop {
+            let new_diff = Readings::get_vec_diff(all_vecs.last().unwrap());
+
+            if new_diff.iter().all(|&x| x == 0) {
+                done = true;
+            }
+
+            all_vecs.push(new_diff);
+            if done {
+                break;
+            }
+        }
+
+        let mut sum = 0;
+        let mut new_values = vec![];
+        for vec in all_vecs.iter().rev() {
+            sum = vec.first().unwrap_or(&0) - sum;
+            new_values.push(sum);
+        }
+
+        sum
+    }
+}
+
+fn get_new_number_sum_1(readings: &[Readings]) -> i32 {
+    let predictions: Vec<i32> = readings
+        .iter()
+        .map(|reading| reading.get_prediction())
+        .collect();
+
+    predictions.iter().sum()
+}
+
+fn get_prev_number_sum_2(readings: &[Readings]) -> i32 {
+    let predictions: Vec<i32> = readings
+        .iter()
+        .map(|reading| reading.get_prev_value())
+        .collect();
+
+    predictions.iter().sum()
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<Readings>> {
+    let mut readings_vec = vec![];
+
+    for (line, num_string) in input.iter().enumerate() {
+        let nums: Vec<i32> = parse::numbers(line, num_string, num_string)?;
+
+        let new_readings = Readings { nums };
+
+        readings_vec.push(new_readings);
+    }
+
+    Ok(readings_vec)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Readings>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        get_new_number_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        get_prev_number_sum_2(input)
+    }
+}
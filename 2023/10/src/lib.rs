@@ -0,0 +1,294 @@
+/*
+we have a grid of pipes with an animal in them
+the pipes a quaranteed to form a circle
+there are also some pipes that arent connected to the current pipe system but we can ignore them
+the pipes look like:
+| north south
+- west east
+L north east
+J north west
+7 west south
+F east south
+
+we also have:
+. empty ground tile
+S the tile animal is on
+
+
+We have to navigate through the loop and see how far we can get away from the animal in the loop and return that number
+*/
+
+use aoc_common::polygon;
+use aoc_common::{Grid, ParseError, ParseResult, Point2, Solution};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Coord {
+    x: u32,
+    y: u32,
+}
+
+impl Coord {
+    fn pos(&self) -> (usize, usize) {
+        (self.x as usize, self.y as usize)
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+enum Dir {
+    North(Coord),
+    East(Coord),
+    South(Coord),
+    West(Coord),
+}
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    symbol: char,
+    valid_moves: Vec<Dir>,
+}
+
+fn get_furthest_location_1(grid: &Grid<Tile>) -> u32 {
+    let animal_tile = grid
+        .cells()
+        .iter()
+        .find(|location| location.symbol == 'S')
+        .unwrap();
+
+    let mut next_move: Dir = animal_tile.valid_moves[0];
+    let mut next_coord = get_direction_coord(&next_move);
+    let mut current_tile = &grid[next_coord.pos()];
+    let mut steps_taken = 1;
+
+    while current_tile.symbol != 'S' {
+        let valid_moves = remove_previous_dir(&current_tile.valid_moves, &next_move);
+        next_move = valid_moves[0];
+        next_coord = get_direction_coord(&next_move);
+        steps_taken += 1;
+        current_tile = &grid[next_coord.pos()];
+    }
+
+    assert!(steps_taken % 2 == 0);
+    steps_taken / 2
+}
+
+// the loop is a polygon with a corner on every one of its tiles, so the enclosed tiles are the lattice
+// points inside it
+fn get_enclosed_tiles_count_2(grid: &Grid<Tile>) -> u32 {
+    let main_loop = get_main_loop(grid);
+
+    polygon::interior_points(&main_loop) as u32
+}
+
+fn get_direction_coord(dir: &Dir) -> Coord {
+    match dir {
+        Dir::North(coord) | Dir::East(coord) | Dir::South(coord) | Dir::West(coord) => *coord,
+    }
+}
+
+/// The coords of the loop tiles, in the order they are walked from the animal
+fn get_main_loop(grid: &Grid<Tile>) -> Vec<Point2> {
+    let (animal_pos, animal_tile) = grid
+        .iter()
+        .find(|(_, location)| location.symbol == 'S')
+        .unwrap();
+
+    let mut main_loop = vec![Point2::from(animal_pos)];
+
+    let mut next_move: Dir = animal_tile.valid_moves[0];
+    let mut next_coord = get_direction_coord(&next_move);
+    let mut current_tile = &grid[next_coord.pos()];
+
+    while current_tile.symbol != 'S' {
+        main_loop.push(Point2::from(next_coord.pos()));
+
+        let valid_moves = remove_previous_dir(&current_tile.valid_moves, &next_move);
+        next_move = valid_moves[0];
+        next_coord = get_direction_coord(&next_move);
+        current_tile = &grid[next_coord.pos()];
+    }
+
+    main_loop
+}
+
+fn remove_previous_dir(directions: &[Dir], prev: &Dir) -> Vec<Dir> {
+    let mut valid_directions = directions.to_vec();
+    match prev {
+        Dir::North(_) => valid_directions.retain(|&x| !matches!(x, Dir::South(_))),
+        Dir::East(_) => valid_directions.retain(|&x| !matches!(x, Dir::West(_))),
+        Dir::South(_) => valid_directions.retain(|&x| !matches!(x, Dir::North(_))),
+        Dir::West(_) => valid_directions.retain(|&x| !matches!(x, Dir::East(_))),
+    }
+    valid_directions
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Grid<Tile>> {
+    let symbols = Grid::parse(input, "|-LJ7F.S", |c| c)?;
+    let tiles = symbols
+        .iter()
+        .map(|((x, y), &symbol)| {
+            let north_coord = Coord {
+                x: x as u32,
+                y: (y as u32).wrapping_sub(1),
+            };
+            let east_coord = Coord {
+                x: (x + 1) as u32,
+                y: y as u32,
+            };
+            let south_coord = Coord {
+                x: x as u32,
+                y: (y + 1) as u32,
+            };
+            let west_coord = Coord {
+                x: (x as u32).wrapping_sub(1),
+                y: y as u32,
+            };
+
+            let valid_moves: Vec<Dir> = match symbol {
+                '|' => vec![Dir::North(north_coord), Dir::South(south_coord)],
+                '-' => vec![Dir::East(east_coord), Dir::West(west_coord)],
+                'L' => vec![Dir::North(north_coord), Dir::East(east_coord)],
+                'J' => vec![Dir::North(north_coord), Dir::West(west_coord)],
+                '7' => vec![Dir::South(south_coord), Dir::West(west_coord)],
+                'F' => vec![Dir::South(south_coord), Dir::East(east_coord)],
+                _ => vec![],
+            };
+
+            Tile {
+                symbol,
+                valid_moves,
+            }
+        })
+        .collect();
+    let mut input_hashmap = Grid::from_vec(symbols.width(), tiles);
+
+    // find the Animal 'S' starting location and determine the valid moves from 'S'
+    let mut valid_animal_moves = vec![];
+    let animal_coords = symbols
+        .find(&'S')
+        .map(|(x, y)| Coord {
+            x: x as u32,
+            y: y as u32,
+        })
+        .ok_or_else(|| ParseError::new(input.len(), 0, "a starting tile 'S'", "end of input"))?;
+
+    {
+        let mut left_coord = animal_coords;
+        left_coord.x = left_coord.x.wrapping_sub(1);
+        if let Some(tile) = input_hashmap.get(left_coord.pos()) {
+            if tile
+                .valid_moves
+                .iter()
+                .any(|dir| matches!(dir, Dir::East(_)))
+            {
+                valid_animal_moves.push(Dir::West(left_coord));
+            }
+        }
+    }
+
+    {
+        let mut up_coord = animal_coords;
+        up_coord.y = up_coord.y.wrapping_sub(1);
+        if let Some(tile) = input_hashmap.get(up_coord.pos()) {
+            if tile
+                .valid_moves
+                .iter()
+                .any(|dir| matches!(dir, Dir::South(_)))
+            {
+                valid_animal_moves.push(Dir::North(up_coord));
+            }
+        }
+    }
+
+    {
+        let mut right_coord = animal_coords;
+        right_coord.x += 1;
+        if let Some(tile) = input_hashmap.get(right_coord.pos()) {
+            if tile
+                .valid_moves
+                .iter()
+                .any(|dir| matches!(dir, Dir::West(_)))
+            {
+                valid_animal_moves.push(Dir::East(right_coord));
+            }
+        }
+    }
+
+    {
+        let mut down_coord = animal_coords;
+        down_coord.y += 1;
+        if let Some(tile) = input_hashmap.get(down_coord.pos()) {
+            if tile
+                .valid_moves
+                .iter()
+                .any(|dir| matches!(dir, Dir::North(_)))
+            {
+                valid_animal_moves.push(Dir::South(down_coord));
+            }
+        }
+    }
+
+    let (x, y) = animal_coords.pos();
+    if valid_animal_moves.len() != 2 {
+        return Err(ParseError::new(
+            y,
+            x,
+            "a starting tile with 2 pipes connecting to it",
+            format!("{} pipes", valid_animal_moves.len()),
+        ));
+    }
+
+    let animal_time = &mut input_hashmap[animal_coords.pos()];
+    animal_time.valid_moves = valid_animal_moves;
+
+    check_main_loop(&input_hashmap, animal_coords)?;
+
+    Ok(input_hashmap)
+}
+
+// the pipes from the animal have to lead back to it without leaving the grid
+fn check_main_loop(grid: &Grid<Tile>, animal_coords: Coord) -> ParseResult<()> {
+    let mut current_coord = animal_coords;
+    let mut next_move = grid[animal_coords.pos()].valid_moves[0];
+
+    loop {
+        let next_coord = get_direction_coord(&next_move);
+        let (x, y) = current_coord.pos();
+        let broken = || {
+            let found = format!("{:?}", grid[(x, y)].symbol);
+            ParseError::new(y, x, "a pipe that connects to the next one", found)
+        };
+
+        let tile = grid.get(next_coord.pos()).ok_or_else(broken)?;
+        if tile.symbol == 'S' {
+            return Ok(());
+        }
+
+        let valid_moves = remove_previous_dir(&tile.valid_moves, &next_move);
+        if tile.valid_moves.len() != 2 || valid_moves.len() != 1 {
+            return Err(broken());
+        }
+
+        current_coord = next_coord;
+        next_move = valid_moves[0];
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Tile>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_furthest_location_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_enclosed_tiles_count_2(input)
+    }
+}
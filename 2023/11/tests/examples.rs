@@ -0,0 +1,13 @@
+use aoc_2023_11::{calculate_path_sum, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 374);
+}
+
+#[test]
+fn part_2() {
+    let example = harness::parse_file::<Day>("example_2.txt");
+    harness::check("example_2.txt", calculate_path_sum(&example, 100), 8410);
+}
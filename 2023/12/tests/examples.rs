@@ -0,0 +1,12 @@
+use aoc_2023_12::Day;
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 21);
+}
+
+#[test]
+fn part_2() {
+    harness::example_2::<Day>("example_2.txt", 525152);
+}
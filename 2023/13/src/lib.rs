@@ -0,0 +1,150 @@
+/*
+we have a valley of ash (.) and rocks (#) filled with mirrors
+we have to figure out where there are mirrors based on the valley terrain
+to find mirrors, we we check for perfect reflections across vertical line between 2 columns
+
+for the answer, we add up the number of columns to the left of mirrors
+and we add 100 x columns above the reflection lines
+
+part 2:
+every mirror has a smudge, in the reflection there is a single (.) or (#) that should be the opposite
+we have to used the fixed reflection even if the old one keeps being valid with the smudge fixed
+
+*/
+
+use aoc_common::{input, parse};
+use aoc_common::{ParseResult, Solution};
+use std::vec;
+
+pub struct Valley {
+    rows: Vec<String>,
+    columns: Vec<String>,
+}
+
+impl Valley {
+    fn from_string(input: &[String]) -> Self {
+        let max_length = input.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        let mut columns = vec![String::new(); max_length];
+
+        for row in input {
+            for (i, ch) in row.chars().enumerate() {
+                columns[i].push(ch);
+            }
+        }
+
+        Valley {
+            rows: input.to_vec(),
+            columns,
+        }
+    }
+
+    fn get_mirror_points(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut row_mirros: Vec<usize> = vec![];
+        let mut columns_mirrors: Vec<usize> = vec![];
+
+        for (i, row_pair) in self.rows.windows(2).enumerate() {
+            let diff_count = row_pair[0]
+                .chars()
+                .zip(row_pair[1].chars())
+                .filter(|(c1, c2)| c1 != c2)
+                .count();
+
+            if diff_count <= 1 {
+                row_mirros.push(i + 1);
+            }
+        }
+
+        for (i, col_pair) in self.columns.windows(2).enumerate() {
+            let diff_count = col_pair[0]
+                .chars()
+                .zip(col_pair[1].chars())
+                .filter(|(c1, c2)| c1 != c2)
+                .count();
+
+            if diff_count <= 1 {
+                columns_mirrors.push(i + 1);
+            }
+        }
+
+        (row_mirros, columns_mirrors)
+    }
+
+    fn get_points(&self, diff: u32) -> u32 {
+        let mut points = 0;
+        let (row_locations, col_locations) = self.get_mirror_points();
+
+        points += self.mirror_points(&self.rows, row_locations, diff) * 100;
+
+        points += self.mirror_points(&self.columns, col_locations, diff);
+
+        points as u32
+    }
+
+    fn mirror_points(&self, valley: &[String], split_points: Vec<usize>, diff: u32) -> usize {
+        for point in split_points {
+            let (left, right) = valley.split_at(point);
+
+            let min_size = left.len().min(right.len());
+            let same_count: usize = left
+                .iter()
+                .rev()
+                .zip(right.iter())
+                .take(min_size)
+                .map(|(l, r)| l.chars().zip(r.chars()).filter(|(lc, rc)| lc != rc).count())
+                .sum();
+
+            if same_count == diff as usize {
+                return point;
+            }
+        }
+        0
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<Valley>> {
+    parse::grid_chars(input, ".#")?;
+
+    Ok(input::blocks(input)
+        .iter()
+        .map(|valley| Valley::from_string(valley.lines))
+        .collect())
+}
+
+fn get_mirror_sum_1(all_valleys: &[Valley]) -> u32 {
+    let points: Vec<u32> = all_valleys
+        .iter()
+        .map(|valley| valley.get_points(0))
+        .collect();
+
+    points.iter().sum()
+}
+
+fn get_mirror_sum_2(all_valleys: &[Valley]) -> u32 {
+    let points: Vec<u32> = all_valleys
+        .iter()
+        .map(|valley| valley.get_points(1))
+        .collect();
+
+    points.iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Valley>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_mirror_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_mirror_sum_2(input)
+    }
+}
@@ -0,0 +1,100 @@
+/*
+we have a collection on round (O) and square (#) rocks in a area. Only the round rocks can be moved
+The round rocks cannot go over the square rocks so if we move all the rocks to a certain direction,
+some of the rocks will roll until the end of the area and some rocks will be stuck on the square rocks
+
+the higher the rock, the more it deforms the platform, we have to count the total amount that the rocks
+deform the platform
+
+part 2:
+
+we move bolders to north, then west, then south, then east 1000000000 times and then count the points
+
+*/
+
+use aoc_common::cycle;
+use aoc_common::{Grid, ParseResult, Solution};
+
+type Platform = Grid<char>;
+
+fn get_total_strain_1(parsed: &Platform) -> u32 {
+    // up points to west, so moving right moves north
+    let mut parsed = parsed.rotate_clockwise();
+    move_right(&mut parsed);
+
+    north_load(&parsed.rotate_counterclockwise())
+}
+
+pub fn get_total_strain_2(parsed: &Platform, cycles: usize) -> u32 {
+    let parsed = cycle::state_after(parsed, spin_cycle, cycles);
+
+    north_load(&parsed)
+}
+
+/// Moves the rocks north, then west, then south and then east
+fn spin_cycle(parsed: &Platform) -> Platform {
+    let mut parsed = parsed.rotate_clockwise(); // up points to west
+    move_right(&mut parsed); // move north
+
+    parsed = parsed.rotate_clockwise(); // up points to south
+    move_right(&mut parsed); // move west
+
+    parsed = parsed.rotate_clockwise(); // up points to east
+    move_right(&mut parsed); // move south
+
+    parsed = parsed.rotate_clockwise(); // up points to north
+    move_right(&mut parsed); // move east
+
+    parsed
+}
+
+fn north_load(area: &Platform) -> u32 {
+    area.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, row)| (i + 1) * row.iter().filter(|&&c| c == 'O').count())
+        .sum::<usize>() as u32
+}
+
+fn move_right(area: &mut Platform) {
+    let mut moved;
+
+    loop {
+        moved = false;
+        for row in area.rows_mut() {
+            for i in (0..row.len() - 1).rev() {
+                if row[i] == 'O' && !['O', '#'].contains(&row[i + 1]) {
+                    row.swap(i, i + 1);
+                    moved = true;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Platform> {
+    Grid::parse(input, ".#O", |c| c)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Platform;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_total_strain_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_total_strain_2(input, 1_000_000_000)
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_2023_14::{get_total_strain_2, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 136);
+}
+
+#[test]
+fn part_2() {
+    let example = harness::parse_file::<Day>("example_2.txt");
+    harness::check(
+        "example_2.txt",
+        get_total_strain_2(&example, 1_000_000_000),
+        64,
+    );
+}
@@ -0,0 +1,142 @@
+/*
+we have a bunch of strings that we need to hash
+get the aschii representation of each char, add it to total, multiply the total by 17, get remainder from total / 256, add the new aschii number
+
+do this to all strings and return the sum
+
+part 2:
+
+we have bunch of lense boxed
+the lensed have a identifier string, a - or = and a lens strenght number
+we add, remove and change the lenses in the boxes based on the - or the = marks
+and in the end, we take the total power of all the boxes with the sum of
+(box num +1) * lens number * lens strenght
+for all the boxes and return the sum
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+use indexmap::IndexMap;
+use regex::Regex;
+use std::collections::HashMap;
+
+enum Sign {
+    Remove,
+    Add(u32),
+}
+
+pub struct HashString {
+    complete_string: String,
+    id_string: String,
+    sign: Sign,
+}
+
+impl HashString {
+    // `input` is a slice of `line_str`, the one line holding every step
+    fn from_string(line: usize, line_str: &str, input: &str) -> ParseResult<Self> {
+        let re = Regex::new(r"^([a-zA-Z]+)([=-])(\d+)?$").unwrap();
+        let captures = re.captures(input).ok_or_else(|| {
+            ParseError::at(line, line_str, input, "a label followed by '-' or '='")
+        })?;
+
+        let id_string = captures[1].to_string();
+
+        let sign = match &captures[2] {
+            "=" => {
+                let add_number = match captures.get(3) {
+                    Some(number) => parse::number(line, line_str, number.as_str())?,
+                    None => return Err(ParseError::at(line, line_str, input, "a lens strength")),
+                };
+
+                Sign::Add(add_number)
+            }
+            _ => Sign::Remove,
+        };
+
+        Ok(HashString {
+            complete_string: input.to_owned(),
+            id_string,
+            sign,
+        })
+    }
+
+    fn get_string_hash(&self, input: &str) -> u32 {
+        let mut total: u32 = 0;
+        for c in input.chars() {
+            assert!(c.is_ascii());
+
+            let ascii_code = c as u8;
+            total += ascii_code as u32;
+            total *= 17;
+            total %= 256;
+        }
+
+        total
+    }
+}
+
+fn get_hash_sum_1(hash_strings: &[HashString]) -> u32 {
+    hash_strings
+        .iter()
+        .map(|s| s.get_string_hash(&s.complete_string))
+        .sum()
+}
+
+fn get_focus_power_sum_2(hash_strings: &[HashString]) -> u32 {
+    let mut lense_boxes: HashMap<u32, IndexMap<String, u32>> = HashMap::new();
+
+    for item in hash_strings {
+        let hash = item.get_string_hash(&item.id_string);
+
+        let current_box = lense_boxes.entry(hash).or_default();
+
+        match item.sign {
+            Sign::Add(lense_num) => {
+                *current_box.entry(item.id_string.clone()).or_insert(0) = lense_num;
+            }
+            Sign::Remove => {
+                current_box.shift_remove(&item.id_string);
+            }
+        }
+    }
+
+    let mut total = 0;
+    for box_num in 0..=256 {
+        if let Some(lens_box) = lense_boxes.get(&box_num) {
+            for (lens_num, power) in lens_box.values().enumerate() {
+                total += (box_num + 1) * (lens_num + 1) as u32 * power;
+            }
+        }
+    }
+    total
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<HashString>> {
+    let line_str = parse::line(input, 0)?;
+
+    line_str
+        .split(",")
+        .map(|s| HashString::from_string(0, line_str, s))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<HashString>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_hash_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_focus_power_sum_2(input)
+    }
+}
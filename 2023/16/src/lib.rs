@@ -0,0 +1,196 @@
+/*
+we have a beam of light starting from top left going right that travels in a area of mirrors, mirrrs can be '/', '\', '-', '|'
+and there are empty spaces represented by '.'
+we always reflect from the mirror 90 degrees, for example going right and hitting \ means that we will travel downward
+but if we hit a head on collision like going right and hitting | then we split the beam going up and down
+the answer is the count of the tiles that we have visited in the area
+
+part 2:
+we can enter from any side going to the dir opposite of that side and we have to get the max tiles visited for any entering direction
+*/
+
+use aoc_common::{Dir4, Grid, ParseResult, Point2, Solution};
+use std::collections::VecDeque;
+
+#[derive(Debug, Hash, Clone, Default)]
+struct VisitedDirs {
+    up: bool,
+    right: bool,
+    down: bool,
+    left: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Beam {
+    pos: Point2,
+    dir: Dir4,
+}
+
+impl Beam {
+    /// The directions the beam leaves a tile in after hitting its mirror
+    fn reflect(&self, mirror: Option<char>) -> Vec<Dir4> {
+        let vertical = matches!(self.dir, Dir4::Up | Dir4::Down);
+        match (mirror, vertical) {
+            (Some('/'), false) | (Some('\\'), true) => vec![self.dir.turn_left()],
+            (Some('/'), true) | (Some('\\'), false) => vec![self.dir.turn_right()],
+            (Some('|'), false) => vec![Dir4::Up, Dir4::Down],
+            (Some('-'), true) => vec![Dir4::Right, Dir4::Left],
+            _ => vec![self.dir],
+        }
+    }
+}
+
+#[derive(Debug, Hash, Clone)]
+struct AreaPos {
+    mirror: Option<char>,
+    visited: bool,
+    dirs_visited: VisitedDirs,
+}
+#[derive(Debug, Clone)]
+pub struct Area {
+    area: Grid<AreaPos>,
+}
+
+impl Area {
+    fn from_grid(grid: &[String]) -> ParseResult<Self> {
+        let area = Grid::parse(grid, "./\\|-", |c| AreaPos {
+            mirror: (c != '.').then_some(c),
+            visited: false,
+            dirs_visited: VisitedDirs::default(),
+        })?;
+        Ok(Area { area })
+    }
+}
+
+fn get_visited_tiles_1(arena: &Area) -> u32 {
+    let starting_beam = Beam {
+        pos: Point2::ORIGIN,
+        dir: Dir4::Right,
+    };
+
+    mark_visited(arena.clone(), starting_beam)
+}
+
+fn get_max_visited_tiles_2(arena: &Area) -> u32 {
+    let max_x = arena.area.width() as i64 - 1;
+    let max_y = arena.area.height() as i64 - 1;
+
+    let mut all_beams: Vec<Beam> = vec![];
+
+    for x in 0..=max_x {
+        all_beams.push(Beam {
+            pos: Point2::new(x, 0),
+            dir: Dir4::Down,
+        });
+        all_beams.push(Beam {
+            pos: Point2::new(x, max_y),
+            dir: Dir4::Up,
+        });
+    }
+
+    for y in 0..=max_y {
+        all_beams.push(Beam {
+            pos: Point2::new(0, y),
+            dir: Dir4::Right,
+        });
+        all_beams.push(Beam {
+            pos: Point2::new(max_x, y),
+            dir: Dir4::Left,
+        });
+    }
+
+    all_beams
+        .into_iter()
+        .map(|beam| mark_visited(arena.clone(), beam))
+        .max()
+        .unwrap()
+}
+
+fn mark_visited(mut arena: Area, starting_beam: Beam) -> u32 {
+    let mut beams: VecDeque<Beam> = VecDeque::new();
+    beams.push_back(starting_beam);
+
+    while let Some(current_beam) = beams.pop_front() {
+        let Some(pos) = current_beam
+            .pos
+            .pos()
+            .and_then(|pos| arena.area.get_mut(pos))
+        else {
+            // the beam left the area
+            continue;
+        };
+
+        if has_already_visited(pos, current_beam.dir) {
+            continue;
+        }
+
+        for dir in current_beam.reflect(pos.mirror) {
+            beams.push_back(Beam {
+                pos: current_beam.pos + dir,
+                dir,
+            });
+        }
+    }
+
+    arena
+        .area
+        .cells()
+        .iter()
+        .filter(|value| value.visited)
+        .count() as u32
+}
+
+fn has_already_visited(cuttent_tile: &mut AreaPos, dir: Dir4) -> bool {
+    cuttent_tile.visited = true;
+    let visited = &mut cuttent_tile.dirs_visited;
+    match dir {
+        Dir4::Up => {
+            if !visited.up {
+                visited.up = true;
+                return false;
+            }
+            true
+        }
+        Dir4::Left => {
+            if !visited.left {
+                visited.left = true;
+                return false;
+            }
+            true
+        }
+        Dir4::Down => {
+            if !visited.down {
+                visited.down = true;
+                return false;
+            }
+            true
+        }
+        Dir4::Right => {
+            if !visited.right {
+                visited.right = true;
+                return false;
+            }
+            true
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Area;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Area::from_grid(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_visited_tiles_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_max_visited_tiles_2(input)
+    }
+}
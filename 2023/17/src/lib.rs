@@ -0,0 +1,131 @@
+/*
+we have to push a hot cart of lava and we have a grid of numbers that indicate how much the lava is cooled on that tile
+we have to push the cart from the top left to the bottom right while losing as little heat as possible
+also the path must not have more than 3 tiles in a straight line
+
+*/
+
+extern crate fxhash;
+use aoc_common::search;
+use aoc_common::{Dir4, Grid, ParseError, ParseResult, Point2, Solution};
+use fxhash::FxHashMap;
+
+#[derive(Clone)]
+struct Node {
+    cost: u16,
+}
+
+impl Node {
+    fn new(cost: u16) -> Self {
+        Node { cost }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+struct Crucible {
+    current_node: Point2,
+    facing_dir: Dir4,
+    straigh_steps: u8,
+}
+
+#[derive(Clone)]
+pub struct NumberGraph {
+    valid_coords: FxHashMap<Point2, Node>,
+}
+
+impl NumberGraph {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let mut valid_coords: FxHashMap<Point2, Node> = FxHashMap::default();
+
+        let digits = Grid::parse(input, "0123456789", |c| c.to_digit(10).unwrap() as u16)?;
+        // the ultra crucible goes 4 tiles before it can turn, so it needs room for that
+        if digits.width() < 5 || digits.height() < 5 {
+            return Err(ParseError::new(
+                input.len(),
+                0,
+                "a grid of at least 5 by 5 blocks",
+                format!("{} by {}", digits.width(), digits.height()),
+            ));
+        }
+        for (pos, &num) in digits.iter() {
+            valid_coords.insert(Point2::from(pos), Node::new(num));
+        }
+
+        Ok(NumberGraph { valid_coords })
+    }
+
+    /// The least heat lost from `start` to `end` when the crucible has to go straight for
+    /// `min_straight` tiles before it can turn and can go straight for at most `max_straight` tiles
+    fn min_heatloss(&self, start: Point2, end: Point2, min_straight: u8, max_straight: u8) -> u16 {
+        let valid_coords = &self.valid_coords;
+        let start = Crucible {
+            current_node: start,
+            facing_dir: Dir4::Right,
+            straigh_steps: 0,
+        };
+
+        let path = search::dijkstra(
+            [start],
+            |&crucible| {
+                let mut moves = vec![];
+                if crucible.straigh_steps < max_straight {
+                    moves.push((crucible.facing_dir, crucible.straigh_steps + 1));
+                }
+                if crucible.straigh_steps >= min_straight {
+                    moves.push((crucible.facing_dir.turn_right(), 1));
+                    moves.push((crucible.facing_dir.turn_left(), 1));
+                }
+
+                moves.into_iter().filter_map(move |(dir, straigh_steps)| {
+                    let next_coords = crucible.current_node + dir;
+                    let node = valid_coords.get(&next_coords)?;
+                    let next = Crucible {
+                        current_node: next_coords,
+                        facing_dir: dir,
+                        straigh_steps,
+                    };
+                    Some((next, node.cost as u64))
+                })
+            },
+            |crucible| crucible.current_node == end,
+        );
+
+        path.unwrap().cost as u16
+    }
+}
+
+fn get_min_heatloss_1(graph: &NumberGraph) -> u16 {
+    let start_node = Point2::ORIGIN;
+
+    let end_node = *graph.valid_coords.keys().max().unwrap();
+
+    graph.min_heatloss(start_node, end_node, 0, 3)
+}
+
+fn get_min_heatloss_2(graph: &NumberGraph) -> u16 {
+    let start_node = Point2::ORIGIN;
+
+    let end_node = *graph.valid_coords.keys().max().unwrap();
+
+    graph.min_heatloss(start_node, end_node, 4, 10)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = NumberGraph;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        NumberGraph::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u16 {
+        get_min_heatloss_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u16 {
+        get_min_heatloss_2(input)
+    }
+}
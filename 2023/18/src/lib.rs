@@ -0,0 +1,111 @@
+/*
+part 1:
+we have a collection of instruction, we dig a trench according to the instructions, then we hollow it out and get the total volume of the trench
+
+part 2:
+the instructions were messed up and we need to parse the hex code given as the wall color to be the the direction and color
+*/
+
+use aoc_common::{parse, polygon};
+use aoc_common::{Dir4, ParseError, ParseResult, Point2, Solution};
+use regex::{Captures, Regex};
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    dir: Dir4,
+    count: u64,
+}
+
+impl Instruction {
+    fn from_string_1(line: usize, input: &str) -> ParseResult<Self> {
+        let captures = Instruction::captures(line, input)?;
+
+        let dir = match &captures[1] {
+            "U" => Dir4::Up,
+            "R" => Dir4::Right,
+            "D" => Dir4::Down,
+            _ => Dir4::Left,
+        };
+
+        Ok(Instruction {
+            dir,
+            count: parse::number(line, input, captures.get(2).unwrap().as_str())?,
+        })
+    }
+
+    fn from_string_2(line: usize, input: &str) -> ParseResult<Self> {
+        let captures = Instruction::captures(line, input)?;
+
+        let dir = match &captures[4] {
+            "0" => Dir4::Right,
+            "1" => Dir4::Down,
+            "2" => Dir4::Left,
+            _ => Dir4::Up,
+        };
+
+        // five hex digits always fit
+        let count: u64 = u32::from_str_radix(&captures[3], 16).unwrap() as u64;
+
+        Ok(Instruction { dir, count })
+    }
+
+    fn captures(line: usize, input: &str) -> ParseResult<Captures<'_>> {
+        let re: Regex = Regex::new(r"^([RDLU]) (\d+) \(#([a-f\d]{5})([0-3])\)$").unwrap();
+
+        re.captures(input)
+            .ok_or_else(|| ParseError::line(line, input, "\"R 6 (#70c710)\""))
+    }
+}
+
+/// The corners of the trench, the dug out cubes are the lattice points on and inside of them
+fn trench_corners(instructions: &[Instruction]) -> Vec<Point2> {
+    instructions
+        .iter()
+        .scan(Point2::ORIGIN, |current_pos, inst| {
+            *current_pos += inst.dir.offset() * inst.count as i64;
+            Some(*current_pos)
+        })
+        .collect()
+}
+
+fn get_trench_volume(instructions: &[Instruction]) -> u64 {
+    let corners = trench_corners(instructions);
+
+    polygon::interior_points(&corners) + polygon::boundary_points(&corners)
+}
+
+fn parse_input_1(input: &[String]) -> ParseResult<Vec<Instruction>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, s)| Instruction::from_string_1(line, s))
+        .collect()
+}
+
+fn parse_input_2(input: &[String]) -> ParseResult<Vec<Instruction>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, s)| Instruction::from_string_2(line, s))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Instruction>, Vec<Instruction>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((parse_input_1(input)?, parse_input_2(input)?))
+    }
+
+    fn part_1((instructions, _): &Self::Input) -> u64 {
+        get_trench_volume(instructions)
+    }
+
+    fn part_2((_, instructions): &Self::Input) -> u64 {
+        get_trench_volume(instructions)
+    }
+}
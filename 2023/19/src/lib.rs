@@ -0,0 +1,373 @@
+/*
+part 1:
+
+part 2:
+every rating goes from 1 to 4000, we have to find how many combinations of ratings get accepted
+the rules split the ranges of ratings into boxes, the volumes of the accepted boxes are summed
+*/
+
+use aoc_common::parse;
+use aoc_common::{Graph, ParseError, ParseResult, Solution};
+use fxhash::FxHashMap;
+use regex::Regex;
+use std::cmp;
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+enum Heading {
+    Condition(bool),
+    Next(String),
+}
+
+impl Heading {
+    fn from_str(heading_str: &str) -> Self {
+        match heading_str {
+            "A" => Heading::Condition(true),
+            "R" => Heading::Condition(false),
+            heading_str => Heading::Next(heading_str.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Comparison {
+    current: char,
+    expected: cmp::Ordering,
+    num: u32,
+}
+impl Comparison {
+    // the ratings of `ratings` that pass the comparison and the ones that don't
+    fn split(&self, ratings: &Range<u32>) -> (Range<u32>, Range<u32>) {
+        let at = match self.expected {
+            cmp::Ordering::Less => self.num,
+            _ => self.num.saturating_add(1),
+        }
+        .clamp(ratings.start, ratings.end);
+
+        let (below, above) = (ratings.start..at, at..ratings.end);
+        match self.expected {
+            cmp::Ordering::Less => (below, above),
+            _ => (above, below),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Part {
+    ratings: FxHashMap<char, u32>,
+    condition: Option<bool>,
+}
+
+impl Part {
+    // "{x=787,m=2655,a=1222,s=2876}"
+    fn from_str(line: usize, part_str: &str) -> ParseResult<Self> {
+        let mut ratings = FxHashMap::default();
+        let qualities_str = part_str
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| ParseError::line(line, part_str, "a part in braces"))?;
+
+        for q in qualities_str.split(",") {
+            let (quality, num) = parse::split_once(line, part_str, q, "=")?;
+
+            let rating = parse::number(line, part_str, num)?;
+            if ratings
+                .insert(quality_char(line, part_str, quality)?, rating)
+                .is_some()
+            {
+                return Err(ParseError::at(
+                    line,
+                    part_str,
+                    quality,
+                    "every rating only once",
+                ));
+            }
+        }
+        if ratings.len() < 4 {
+            return Err(ParseError::line(
+                line,
+                part_str,
+                "a rating for each of x, m, a and s",
+            ));
+        }
+
+        Ok(Part {
+            ratings,
+            condition: None,
+        })
+    }
+}
+
+// a single one of the x, m, a or s ratings, `quality` is a slice of `line_str`
+fn quality_char(line: usize, line_str: &str, quality: &str) -> ParseResult<char> {
+    match quality {
+        "x" | "m" | "a" | "s" => Ok(quality.chars().next().unwrap()),
+        _ => Err(ParseError::at(
+            line,
+            line_str,
+            quality,
+            "'x', 'm', 'a' or 's'",
+        )),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PartComp {
+    comp: Option<Comparison>,
+    heading: Heading,
+}
+
+impl PartComp {
+    // "a<2006:qkq" or just "rfg", `comp_str` is a slice of `line_str`
+    fn from_str(line: usize, line_str: &str, comp_str: &str) -> ParseResult<Self> {
+        let Some((comp_str, next)) = comp_str.split_once(":") else {
+            return Ok(PartComp {
+                comp: None,
+                heading: Heading::from_str(comp_str),
+            });
+        };
+        if let Some(colon) = next.find(':') {
+            return Err(ParseError::at(
+                line,
+                line_str,
+                &next[colon..colon + 1],
+                "',' between the rules",
+            ));
+        }
+
+        let (curr_str, expected, num_str) = if let Some((curr, num)) = comp_str.split_once("<") {
+            (curr, cmp::Ordering::Less, num)
+        } else {
+            let (curr, num) = parse::split_once(line, line_str, comp_str, ">")?;
+            (curr, cmp::Ordering::Greater, num)
+        };
+
+        Ok(PartComp {
+            comp: Some(Comparison {
+                current: quality_char(line, line_str, curr_str)?,
+                expected,
+                num: parse::number(line, line_str, num_str)?,
+            }),
+            heading: Heading::from_str(next),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Workflows {
+    workflows: FxHashMap<String, Vec<PartComp>>,
+    parts: Vec<Part>,
+}
+
+impl Workflows {
+    fn from_str(input: &[String]) -> ParseResult<Self> {
+        let mut workflows: FxHashMap<String, Vec<PartComp>> = FxHashMap::default();
+        let mut parts = vec![];
+
+        let mut headings = vec![];
+        let mut workflow_lines = vec![];
+        let mut is_workflow = true;
+
+        let workflow_regex = Regex::new(r"^([a-z]+)\{(.*)\}$").unwrap();
+
+        for (i, line) in input.iter().enumerate() {
+            if line.is_empty() {
+                is_workflow = false;
+                continue;
+            }
+
+            if is_workflow {
+                let caps = workflow_regex.captures(line).ok_or_else(|| {
+                    ParseError::line(i, line, "a workflow like \"px{a<2006:qkq,rfg}\"")
+                })?;
+
+                let mut part_comps = vec![];
+
+                let name = caps.get(1).unwrap().as_str();
+                let conds_str = caps.get(2).unwrap().as_str();
+                let mut last_rule = "";
+                for c in conds_str.split(",") {
+                    part_comps.push(PartComp::from_str(i, line, c)?);
+                    headings.push((i, name, c.split_once(':').map_or(c, |(_, next)| next)));
+                    last_rule = c;
+                }
+
+                // a part that fails every comparison has to go somewhere
+                if part_comps
+                    .last()
+                    .is_some_and(|part_comp| part_comp.comp.is_some())
+                {
+                    return Err(ParseError::at(
+                        i,
+                        line,
+                        last_rule,
+                        "a last rule without a condition",
+                    ));
+                }
+
+                workflows.insert(name.to_owned(), part_comps);
+                workflow_lines.push((i, name));
+            } else {
+                parts.push(Part::from_str(i, line)?);
+            }
+        }
+
+        // every part starts at "in" and can only be sent to workflows that exist
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new(
+                input.len(),
+                0,
+                "a workflow called \"in\"",
+                "end of input",
+            ));
+        }
+        let mut next_workflows = Graph::new();
+        for (i, name, heading) in headings {
+            if matches!(heading, "A" | "R") {
+                continue;
+            }
+            if !workflows.contains_key(heading) {
+                return Err(ParseError::at(
+                    i,
+                    &input[i],
+                    heading,
+                    "'A', 'R' or the name of a workflow",
+                ));
+            }
+            next_workflows.add_edge(name, heading);
+        }
+
+        // a part sent around a loop of workflows would never be accepted or rejected
+        if let Err(cycle) = next_workflows.topological_sort() {
+            let name = *next_workflows.name(cycle[0]);
+            let &(i, name) = workflow_lines.iter().find(|&&(_, n)| n == name).unwrap();
+            return Err(ParseError::at(
+                i,
+                &input[i],
+                name,
+                "a workflow that doesn't lead back to itself",
+            ));
+        }
+
+        Ok(Workflows { workflows, parts })
+    }
+}
+
+fn accepted_part_quality_sum_1(all_workflows: &Workflows) -> u64 {
+    let mut all_workflows = all_workflows.clone();
+
+    for part in all_workflows.parts.iter_mut() {
+        let mut workflow_str = "in".to_owned();
+        while part.condition.is_none() {
+            for part_comp in all_workflows.workflows.get(&workflow_str).unwrap() {
+                // compare the part quality with the workflow comparison step
+                let comp_result = if let Some(comp) = &part_comp.comp {
+                    part.ratings[&comp.current].cmp(&comp.num) == comp.expected
+                } else {
+                    true
+                };
+
+                if comp_result {
+                    match &part_comp.heading {
+                        Heading::Condition(cond) => part.condition = Some(*cond),
+                        Heading::Next(heading) => workflow_str = heading.clone(),
+                    }
+
+                    break; // go to new workflow
+                } else {
+                    continue; // continue in the current workflow
+                }
+            }
+        }
+    }
+
+    all_workflows
+        .parts
+        .iter()
+        .filter(|part| matches!(part.condition, Some(true)))
+        .map(|part| part.ratings.values().map(|&v| v as u64).sum::<u64>())
+        .sum()
+}
+
+/// A box of x, m, a and s ratings, with the workflows it went through from "in"
+#[derive(Debug, Clone)]
+pub struct RatingBox {
+    pub ratings: FxHashMap<char, Range<u32>>,
+    pub workflows: Vec<String>,
+}
+
+impl RatingBox {
+    pub fn combinations(&self) -> u64 {
+        self.ratings.values().map(|r| r.len() as u64).product()
+    }
+}
+
+/// Every box of ratings from 1 to 4000 that gets accepted, the boxes don't overlap
+pub fn accepted_boxes(all_workflows: &Workflows) -> Vec<RatingBox> {
+    let mut accepted = vec![];
+    let mut queue = vec![RatingBox {
+        ratings: "xmas".chars().map(|quality| (quality, 1..4001)).collect(),
+        workflows: vec!["in".to_owned()],
+    }];
+
+    while let Some(mut current) = queue.pop() {
+        let mut to_heading = |rating_box: RatingBox, heading: &Heading| match heading {
+            Heading::Condition(true) => accepted.push(rating_box),
+            Heading::Condition(false) => {}
+            Heading::Next(next) => {
+                let mut rating_box = rating_box;
+                rating_box.workflows.push(next.clone());
+                queue.push(rating_box);
+            }
+        };
+
+        let workflow = current.workflows.last().unwrap();
+        for part_comp in all_workflows.workflows.get(workflow).unwrap() {
+            let Some(comp) = &part_comp.comp else {
+                to_heading(current, &part_comp.heading);
+                break; // everything that's left goes this way
+            };
+
+            let (passed, failed) = comp.split(&current.ratings[&comp.current]);
+            if !passed.is_empty() {
+                let mut passed_box = current.clone();
+                passed_box.ratings.insert(comp.current, passed);
+                to_heading(passed_box, &part_comp.heading);
+            }
+
+            if failed.is_empty() {
+                break;
+            }
+            current.ratings.insert(comp.current, failed);
+        }
+    }
+
+    accepted
+}
+
+fn accepted_combinations_sum_2(all_workflows: &Workflows) -> u64 {
+    accepted_boxes(all_workflows)
+        .iter()
+        .map(RatingBox::combinations)
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Workflows;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Workflows::from_str(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        accepted_part_quality_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        accepted_combinations_sum_2(input)
+    }
+}
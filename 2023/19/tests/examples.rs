@@ -0,0 +1,23 @@
+use aoc_2023_19::{accepted_boxes, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 19114);
+}
+
+#[test]
+fn part_2() {
+    harness::example_2::<Day>("example_2.txt", 167409079868000);
+}
+
+#[test]
+fn accepted_workflow_paths() {
+    let workflows = harness::parse_file::<Day>("example_2.txt");
+    let boxes = accepted_boxes(&workflows);
+    assert_eq!(boxes.len(), 9);
+
+    let largest = boxes.iter().max_by_key(|b| b.combinations()).unwrap();
+    assert_eq!(largest.workflows, ["in", "qqz", "qs"]);
+    assert_eq!(largest.combinations(), 35328000000000);
+}
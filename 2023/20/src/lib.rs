@@ -0,0 +1,274 @@
+/*
+part 1:
+we have modules communicating to eachother with low pulses or high pulses
+
+different modules:
+(%) flipflop:
+    on or off, start with off
+    ignores high pulse
+    low pulse flips it
+        was off -> high pulse & flip
+        was on -> low pulse & flip
+
+(&) conjunction:
+    rememebers received pulses
+    starts with remembering low pulses
+        if all inputs are high, it sends low
+        else send high pulse
+
+broadcaster:
+    receives a pulse
+    sends the same pulse to all the destinations
+
+part 2:
+
+*/
+
+use aoc_common::{math, parse};
+use aoc_common::{ParseError, ParseResult, Solution};
+use fxhash::FxHashMap;
+use std::collections::VecDeque;
+use std::vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModType {
+    Flipflop,
+    Conjunction,
+    Broadcaster,
+    Button,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Module {
+    mod_type: ModType,
+    state: bool,
+    send_queue: VecDeque<bool>,
+    received: FxHashMap<String, bool>,
+    send_targets: Vec<String>,
+}
+
+impl Module {
+    fn new(module: ModType, targets: Vec<String>) -> Self {
+        Module {
+            mod_type: module,
+            state: false,
+            send_queue: VecDeque::new(),
+            received: FxHashMap::default(),
+            send_targets: targets,
+        }
+    }
+
+    fn receive(&mut self, sender: String, high_pulse: bool) {
+        match self.mod_type {
+            ModType::Flipflop => {
+                if !high_pulse {
+                    self.send_queue.push_back(!self.state);
+                    self.state = !self.state;
+                }
+            }
+            ModType::Conjunction => {
+                self.received.insert(sender, high_pulse);
+                let all_high = self.received.values().all(|&high_pulse| high_pulse);
+                self.send_queue.push_back(!all_high);
+            }
+            ModType::Broadcaster => self.send_queue.push_back(high_pulse),
+
+            ModType::Button => unreachable!(),
+        }
+    }
+
+    fn send(&mut self) -> Option<bool> {
+        match self.mod_type {
+            ModType::Flipflop | ModType::Broadcaster | ModType::Conjunction => {
+                self.send_queue.pop_front()
+            }
+            ModType::Button => Some(false),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllModules {
+    modules: FxHashMap<String, Module>,
+}
+impl AllModules {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let mut modules: FxHashMap<String, Module> = FxHashMap::default();
+        modules.insert(
+            "button".to_owned(),
+            Module::new(ModType::Button, vec!["broadcaster".to_owned()]),
+        );
+
+        let mut senders = vec![];
+
+        let mut all_receivers = vec![];
+
+        for (i, line) in input.iter().enumerate() {
+            let (send, receivers_str) = parse::split_once(i, line, line, " -> ")?;
+            let receivers: Vec<String> = receivers_str.split(", ").map(|s| s.to_owned()).collect();
+
+            let (mod_type, send_name) = if let Some(name) = send.strip_prefix("%") {
+                (ModType::Flipflop, name)
+            } else if let Some(name) = send.strip_prefix("&") {
+                (ModType::Conjunction, name)
+            } else if send == "broadcaster" {
+                (ModType::Broadcaster, send)
+            } else {
+                return Err(ParseError::at(
+                    i,
+                    line,
+                    send,
+                    "'%' or '&' before a module name, or \"broadcaster\"",
+                ));
+            };
+
+            senders.push(send_name);
+            all_receivers.push(receivers.clone());
+
+            let new_module = Module::new(mod_type, receivers);
+            modules.insert(send_name.to_owned(), new_module);
+        }
+
+        //let conjunctions: Vec<&mut Module> = modules
+        //    .values_mut()
+        //    .filter(|m| m.mod_type == ModType::Conjunction)
+        //    .collect();
+
+        for (sender, receivers) in senders.iter().zip(all_receivers) {
+            for rec in receivers {
+                if let Some(module) = modules.get_mut(&rec) {
+                    if module.mod_type == ModType::Conjunction {
+                        module.received.insert(sender.to_string(), false);
+                    }
+                }
+            }
+        }
+
+        Ok(AllModules { modules })
+    }
+}
+
+pub fn pulse_total_sum_1(all_modules: &AllModules, sent_pulses: u32) -> u32 {
+    let mut all_modules = all_modules.clone();
+    let mut low_pulses = 0;
+    let mut high_pulses = 0;
+
+    for _ in 0..sent_pulses {
+        let mut module_queue = VecDeque::from(vec!["button".to_owned()]);
+
+        while let Some(module_str) = module_queue.pop_front() {
+            let (send_sign, send_targets) = {
+                let current_module = all_modules.modules.get_mut(&module_str).unwrap();
+                if let Some(return_sng) = current_module.send() {
+                    let targets = current_module.send_targets.clone();
+
+                    if return_sng {
+                        high_pulses += targets.len() as u32;
+                    } else {
+                        low_pulses += targets.len() as u32;
+                    }
+
+                    (return_sng, targets)
+                } else {
+                    continue;
+                }
+            };
+
+            for target_str in send_targets {
+                if let Some(target_mod) = all_modules.modules.get_mut(&target_str) {
+                    target_mod.receive(module_str.clone(), send_sign);
+                    module_queue.push_back(target_str);
+                }
+            }
+        }
+    }
+
+    low_pulses * high_pulses
+}
+
+fn pulse_total_sum_2(all_modules: &AllModules) -> u64 {
+    let mut all_modules = all_modules.clone();
+
+    // single module feeding the rx module, the examples don't have one
+    let Some(rx_feeder) = all_modules
+        .modules
+        .iter()
+        .find(|(_, val)| val.send_targets.contains(&"rx".to_string()))
+        .map(|(key, _)| key)
+    else {
+        return 0;
+    };
+
+    // feeders that feed rx_feeder
+    // all these feeders need to output high signal in order to rx_feeder to trigger
+    let rx_feed_feeders: Vec<String> = all_modules
+        .modules
+        .iter()
+        .filter(|(_, val)| val.send_targets.contains(rx_feeder))
+        .map(|(key, _)| key.to_owned())
+        .collect();
+
+    let mut feeding_map = FxHashMap::default();
+
+    let mut break_cycle = false;
+
+    let mut button_presses = 0;
+    while !break_cycle {
+        let mut module_queue = VecDeque::from(vec![("button".to_owned(), 0)]);
+        button_presses += 1;
+
+        while let Some((module_str, _)) = module_queue.pop_front() {
+            let (send_sign, send_targets) = {
+                let current_module = all_modules.modules.get_mut(&module_str).unwrap();
+                if let Some(return_sng) = current_module.send() {
+                    let targets = current_module.send_targets.clone();
+
+                    (return_sng, targets)
+                } else {
+                    continue;
+                }
+            };
+
+            for target_str in send_targets {
+                if let Some(target_mod) = all_modules.modules.get_mut(&target_str) {
+                    target_mod.receive(module_str.clone(), send_sign);
+
+                    if rx_feed_feeders.contains(&target_str)
+                        && !feeding_map.contains_key(&target_str)
+                        && !send_sign
+                    {
+                        feeding_map.insert(target_str.clone(), button_presses);
+                    }
+
+                    module_queue.push_back((target_str, button_presses));
+                }
+            }
+
+            if feeding_map.len() == rx_feed_feeders.len() {
+                break_cycle = true;
+            }
+        }
+    }
+
+    math::lcm_all(feeding_map.into_values())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = AllModules;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        AllModules::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        pulse_total_sum_1(input, 1000)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        pulse_total_sum_2(input)
+    }
+}
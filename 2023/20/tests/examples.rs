@@ -0,0 +1,8 @@
+use aoc_2023_20::{pulse_total_sum_1, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check("example_1.txt", pulse_total_sum_1(&example, 1000), 11687500);
+}
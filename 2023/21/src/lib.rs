@@ -0,0 +1,147 @@
+/*
+part 1:
+we have a garden with bocks,
+we have to determine all the tiles that we could be on after taking 64 steps
+
+part 2:
+the garden repeats infinitely in every direction,
+we have to determine how many tiles we could be on after taking 26501365 steps
+*/
+
+use aoc_common::search;
+use aoc_common::{Grid, ParseError, ParseResult, Point2, Solution};
+
+#[derive(Clone)]
+pub struct Garden {
+    start_pos: (usize, usize),
+    // `true` for garden plots, `false` for rocks
+    plots: Grid<bool>,
+}
+
+impl Garden {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let garden = Grid::parse(input, ".#S", |c| c)?;
+        // the walk in part 2 spreads out over whole gardens at a time
+        if garden.height() != garden.width() {
+            let line = garden.height().min(garden.width());
+            let found = input.get(line).map_or("end of input", |_| "another row");
+            return Err(ParseError::new(line, 0, "a square garden", found));
+        }
+
+        let mut starts = garden.find_all(&'S');
+        let (Some(start_pos), None) = (starts.next(), starts.next()) else {
+            return Err(ParseError::new(
+                0,
+                0,
+                "a single start 'S'",
+                "a garden without one",
+            ));
+        };
+
+        Ok(Garden {
+            start_pos,
+            plots: garden.map(|&c| c != '#'),
+        })
+    }
+
+    fn size(&self) -> u64 {
+        self.plots.width() as u64
+    }
+
+    // the distances to every plot of the repeated map that is at most `radius` steps away
+    fn wrapped_distances(&self, radius: u64) -> Vec<u64> {
+        let start = Point2::from(self.start_pos);
+        search::bfs([start], |&pos| {
+            pos.neighbours4().filter(move |next| {
+                next.manhattan(&start) <= radius && *self.plots.get_wrapping(next.x(), next.y())
+            })
+        })
+        .into_values()
+        .filter(|&distance| distance <= radius)
+        .collect()
+    }
+}
+
+pub fn reachable_garden_spots_1(garden: &Garden, steps: u32) -> u32 {
+    let plots = &garden.plots;
+    let distances: Vec<u64> = search::bfs([garden.start_pos], |&pos| {
+        plots.neighbours4(pos).filter(move |&next| plots[next])
+    })
+    .into_values()
+    .collect();
+
+    count_reachable(&distances, steps as u64) as u32
+}
+
+// any tile reached earlier can be reached again by stepping back and forth, as long as the
+// remaining steps are even
+fn count_reachable(distances: &[u64], steps: u64) -> u64 {
+    distances
+        .iter()
+        .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+        .count() as u64
+}
+
+/// The tiles we could be on after `steps` steps in the infinite garden, by walking all of them
+pub fn reachable_wrapped_spots(garden: &Garden, steps: u64) -> u64 {
+    count_reachable(&garden.wrapped_distances(steps), steps)
+}
+
+/// The tiles we could be on after `steps` steps in the infinite garden. Every `size` steps the
+/// walk gets one more ring of gardens further, so once the rings settle the tiles reached after
+/// `steps % size + k * size` steps grow quadratically with `k`.
+pub fn reachable_infinite_garden_spots(garden: &Garden, steps: u64) -> u64 {
+    let size = garden.size();
+    let offset = steps % size;
+
+    let mut periods = 4;
+    loop {
+        let radius = offset + periods * size;
+        if radius >= steps {
+            return reachable_wrapped_spots(garden, steps);
+        }
+
+        let distances = garden.wrapped_distances(radius);
+        let counts: Vec<i64> = (0..=periods)
+            .map(|k| count_reachable(&distances, offset + k * size) as i64)
+            .collect();
+        let second_differences: Vec<i64> = counts
+            .windows(3)
+            .map(|window| window[2] - 2 * window[1] + window[0])
+            .collect();
+
+        let settled = &second_differences[second_differences.len() - 3..];
+        if settled.iter().all(|&difference| difference == settled[0]) {
+            let last = counts[periods as usize];
+            let first_difference = last - counts[periods as usize - 1];
+            let k = ((steps - radius) / size) as i64;
+            return (last + k * first_difference + settled[0] * k * (k + 1) / 2) as u64;
+        }
+
+        periods *= 2;
+    }
+}
+
+fn reachable_garden_spots_2(garden: &Garden) -> u64 {
+    reachable_infinite_garden_spots(garden, 26501365)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Garden;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Garden::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        reachable_garden_spots_1(input, 64)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        reachable_garden_spots_2(input)
+    }
+}
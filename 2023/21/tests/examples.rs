@@ -0,0 +1,27 @@
+use aoc_2023_21::{
+    reachable_garden_spots_1, reachable_infinite_garden_spots, reachable_wrapped_spots, Day,
+};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check("example_1.txt", reachable_garden_spots_1(&example, 6), 16);
+}
+
+#[test]
+fn part_2() {
+    let example = harness::parse_file::<Day>("example_2.txt");
+    for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+        harness::check(
+            "example_2.txt",
+            reachable_wrapped_spots(&example, steps),
+            expected,
+        );
+    }
+
+    for (steps, expected) in [(100, 6536), (500, 167004), (1000, 668697), (5000, 16733044)] {
+        let spots = reachable_infinite_garden_spots(&example, steps);
+        harness::check("example_2.txt", spots, expected);
+    }
+}
@@ -0,0 +1,310 @@
+/*
+part 1:
+we have a collection of pillars in a falling state
+we get the base coords for both sides of the pillars
+the pillars have to fall down until they hit a ground or are stopped by another pillar
+then from the pillars that have fallen, we have to figure out which ones are safe to delete without disturbing any other pillars
+
+and the answer is the count of pillars that are safe to delete
+
+part 2:
+now we have to figure out for every pillar how many other pillars would fall if it was deleted
+
+and the answer is the sum of the pillars that would fall
+*/
+
+use aoc_common::parse;
+use aoc_common::{Graph, ParseError, ParseResult, Point3, Solution};
+use ndarray::{s, Array3, ArrayViewMut3, Dim};
+use std::iter;
+
+type ID = i16;
+
+/// The pillar holding up the lowest pillars in the support graphs
+const GROUND: ID = -1;
+
+/// One block up, the height is stored as y
+const UP: Point3 = Point3::new(0, 1, 0);
+
+// `input` is a slice of `line_str`, z is stored as y so that y is the height
+fn parse_point(line: usize, line_str: &str, input: &str) -> ParseResult<Point3> {
+    let (x_str, rest) = parse::split_once(line, line_str, input, ",")?;
+    let (z_str, y_str) = parse::split_once(line, line_str, rest, ",")?;
+
+    // a negative coordinate would be outside the arena
+    let coord = |coord_str: &str| match parse::number(line, line_str, coord_str)? {
+        coord if coord < 0 => Err(ParseError::at(
+            line,
+            line_str,
+            coord_str,
+            "a coordinate that isn't negative",
+        )),
+        coord => Ok(coord),
+    };
+
+    Ok(Point3::new(coord(x_str)?, coord(y_str)?, coord(z_str)?))
+}
+
+#[derive(Clone)]
+pub struct Arena {
+    blocks: Array3<ID>,
+}
+
+impl Arena {
+    fn get_blocks(
+        &self,
+        start: &Point3,
+        end: &Point3,
+    ) -> ndarray::ArrayBase<ndarray::ViewRepr<&ID>, Dim<[usize; 3]>> {
+        self.blocks.slice(s![
+            (start.x() as usize)..=(end.x() as usize),
+            (start.y() as usize)..=(end.y() as usize),
+            (start.z() as usize)..=(end.z() as usize)
+        ])
+    }
+
+    fn get_blocks_mut(&mut self, start: &Point3, end: &Point3) -> ArrayViewMut3<'_, ID> {
+        self.blocks.slice_mut(s![
+            (start.x() as usize)..=(end.x() as usize),
+            (start.y() as usize)..=(end.y() as usize),
+            (start.z() as usize)..=(end.z() as usize)
+        ])
+    }
+
+    fn get_pillars_ids(&self, start: &Point3, end: &Point3) -> Vec<ID> {
+        let blocks = self.get_blocks(start, end);
+        let mut id_list: Vec<&ID> = blocks.iter().collect();
+        id_list.sort();
+        id_list.dedup();
+        id_list.retain(|&&id| id != -1);
+        id_list.iter().map(|id| **id).collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Pillar {
+    id: ID,
+    start: Point3,
+    end: Point3,
+}
+
+impl Pillar {
+    fn from_string(line: usize, input: &str, id: ID) -> ParseResult<Self> {
+        let (start_str, end_str) = parse::split_once(line, input, input, "~")?;
+
+        let start = parse_point(line, input, start_str)?;
+        let end = parse_point(line, input, end_str)?;
+
+        Ok(Pillar { id, start, end })
+    }
+
+    fn get_under(&self) -> (Point3, Point3) {
+        (self.start - UP, self.end - UP)
+    }
+}
+
+// lets every pillar fall until it lands, the pillars come out sorted by their lowest block
+fn settle(pillars: &[Pillar], arena: &Arena) -> (Vec<Pillar>, Arena) {
+    let mut pillars = pillars.to_vec();
+    let mut arena = arena.clone();
+
+    for pillar in pillars.iter_mut() {
+        loop {
+            let blocks = arena.get_blocks(&pillar.start, &pillar.end);
+
+            if blocks.iter().all(|block_id| *block_id == -1) {
+                if pillar.start.y() == 0 || pillar.end.y() == 0 {
+                    // hit the ground
+                    let mutable_blocks = arena.get_blocks_mut(&pillar.start, &pillar.end);
+
+                    for block in mutable_blocks {
+                        *block = pillar.id;
+                    }
+                    break;
+                } else {
+                    // in the air
+                    pillar.start -= UP;
+                    pillar.end -= UP;
+                }
+            } else {
+                // collided with another pillar
+                pillar.start += UP;
+                pillar.end += UP;
+
+                let mutable_blocks = arena.get_blocks_mut(&pillar.start, &pillar.end);
+
+                for block in mutable_blocks {
+                    *block = pillar.id;
+                }
+                break;
+            }
+        }
+    }
+
+    pillars.sort_by_key(|pillar| pillar.start.y().min(pillar.end.y()));
+
+    (pillars, arena)
+}
+
+/// Which pillars rest on which once they have all fallen. Both graphs have the same node ids, with
+/// the ground as the pillar `-1`.
+pub struct Supports {
+    /// an edge from every pillar to the pillars resting on it
+    pub above: Graph<ID>,
+    /// an edge from every pillar to the pillars it rests on
+    pub below: Graph<ID>,
+}
+
+impl Supports {
+    pub fn new(pillars: &[Pillar], arena: &Arena) -> Self {
+        let (pillars, arena) = settle(pillars, arena);
+
+        let (mut above, mut below) = (Graph::new(), Graph::new());
+        for id in iter::once(GROUND).chain(pillars.iter().map(|pillar| pillar.id)) {
+            above.add_node(id);
+            below.add_node(id);
+        }
+
+        for pillar in &pillars {
+            let under_ids = if pillar.start.y().min(pillar.end.y()) == 0 {
+                vec![GROUND]
+            } else {
+                let (under_start, under_end) = pillar.get_under();
+                let mut under_ids = arena.get_pillars_ids(&under_start, &under_end);
+                under_ids.retain(|&id| id != pillar.id);
+                under_ids
+            };
+
+            for under_id in under_ids {
+                above.add_edge(under_id, pillar.id);
+                below.add_edge(pillar.id, under_id);
+            }
+        }
+
+        Supports { above, below }
+    }
+
+    /// How many other pillars fall when a pillar is disintegrated, for every pillar. Those are the
+    /// pillars it dominates, every way down from them to the ground goes through it.
+    pub fn falling_counts(&self) -> Vec<(ID, u32)> {
+        // the ground comes first, then every pillar after the ones it rests on
+        let order = self.above.topological_sort().unwrap();
+        let ground = self.above.id(&GROUND).unwrap();
+
+        let mut dominator = vec![ground; self.above.len()];
+        let mut depth = vec![0; self.above.len()];
+        for &id in &order[1..] {
+            // the closest pillar all the supporting pillars have in common
+            let closest = self
+                .below
+                .neighbours(id)
+                .iter()
+                .copied()
+                .reduce(|mut a, mut b| {
+                    while a != b {
+                        if depth[a] < depth[b] {
+                            b = dominator[b];
+                        } else {
+                            a = dominator[a];
+                        }
+                    }
+                    a
+                });
+
+            dominator[id] = closest.unwrap();
+            depth[id] = depth[dominator[id]] + 1;
+        }
+
+        let mut dominated = vec![0; self.above.len()];
+        for &id in order[1..].iter().rev() {
+            dominated[dominator[id]] += dominated[id] + 1;
+        }
+
+        order[1..]
+            .iter()
+            .map(|&id| (*self.above.name(id), dominated[id]))
+            .collect()
+    }
+}
+
+fn safe_to_disintegrate_count_1(pillars: &[Pillar], arena: &Arena) -> u32 {
+    let supports = Supports::new(pillars, arena);
+
+    // there are 2 ways the pillar is safe to disintegrage:
+    //  1. the pillar isnt supporting any other pillars
+    //  2. all the pillars that are ontop are supported by multiple pillars
+
+    pillars
+        .iter()
+        .filter(|pillar| {
+            let id = supports.above.id(&pillar.id).unwrap();
+            supports
+                .above
+                .neighbours(id)
+                .iter()
+                .all(|&above_id| supports.below.neighbours(above_id).len() >= 2)
+        })
+        .count() as u32 // count of the pillars that are safe to remove
+}
+
+fn chain_reaction_sum_2(pillars: &[Pillar], arena: &Arena) -> u32 {
+    Supports::new(pillars, arena)
+        .falling_counts()
+        .iter()
+        .map(|(_, falling)| falling)
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Pillar>, Arena);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((pillars, arena): &Self::Input) -> u32 {
+        safe_to_disintegrate_count_1(pillars, arena)
+    }
+
+    fn part_2((pillars, arena): &Self::Input) -> u32 {
+        chain_reaction_sum_2(pillars, arena)
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(Vec<Pillar>, Arena)> {
+    let mut pillars: Vec<Pillar> = input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let id = ID::try_from(i)
+                .map_err(|_| ParseError::line(i, line, format!("at most {} pillars", ID::MAX)))?;
+            Pillar::from_string(i, line, id)
+        })
+        .collect::<ParseResult<_>>()?;
+
+    let size = pillars
+        .iter()
+        .flat_map(|pillar| [pillar.start, pillar.end])
+        .fold(Point3::ORIGIN, |max, point| {
+            Point3::new(
+                max.x().max(point.x()),
+                max.y().max(point.y()),
+                max.z().max(point.z()),
+            )
+        })
+        + Point3::new(1, 1, 1);
+
+    // sort to min y
+    pillars.sort_by_key(|pillar| pillar.start.y().min(pillar.end.y()));
+
+    let blocks = Array3::from_elem(
+        (size.x() as usize, size.y() as usize, size.z() as usize),
+        -1,
+    );
+
+    Ok((pillars, Arena { blocks }))
+}
@@ -0,0 +1,208 @@
+/*
+part 1:
+we have a path with slopes, and we need to determine the longest path possible
+there are only single lane paths and no ways to have a roundabout route
+
+part 2:
+now we treat all paths as walkable and there can be roundabouts, we cannot walk the same path twice
+we have to find the longest path
+*/
+
+use aoc_common::{Dir4, Grid, ParseError, ParseResult, Point2, Solution};
+use aoc_common::{parse, search};
+use fxhash::FxHashMap;
+use rayon::prelude::*;
+
+enum Path {
+    Normal,
+    Dir(Dir4),
+}
+
+pub struct Trails {
+    trail_map: FxHashMap<Point2, Path>,
+}
+
+impl Trails {
+    fn from_string_1(input: &[String]) -> ParseResult<Trails> {
+        let mut trail: FxHashMap<Point2, Path> = FxHashMap::default();
+
+        for (y, y_line) in input.iter().enumerate() {
+            for (x, x_char) in y_line.char_indices() {
+                let path_type = match x_char {
+                    '#' => continue,
+                    '.' => Path::Normal,
+                    '^' | '>' | 'v' | '<' => Path::Dir(Dir4::from_arrow(x_char).unwrap()),
+                    _ => {
+                        return Err(parse::unexpected_char(
+                            y,
+                            x,
+                            x_char,
+                            "a path, forest or slope",
+                        ));
+                    }
+                };
+
+                trail.insert(Point2::from((x, y)), path_type);
+            }
+        }
+
+        Ok(Trails { trail_map: trail })
+    }
+
+    fn from_string_2(input: &[String]) -> ParseResult<Trails> {
+        let mut trail: FxHashMap<Point2, Path> = FxHashMap::default();
+
+        for (y, y_line) in input.iter().enumerate() {
+            for (x, x_char) in y_line.char_indices() {
+                let path_type = match x_char {
+                    '#' => continue,
+                    '.' | '^' | '>' | 'v' | '<' => Path::Normal,
+                    _ => {
+                        return Err(parse::unexpected_char(
+                            y,
+                            x,
+                            x_char,
+                            "a path, forest or slope",
+                        ));
+                    }
+                };
+
+                trail.insert(Point2::from((x, y)), path_type);
+            }
+        }
+
+        Ok(Trails { trail_map: trail })
+    }
+
+    fn get_start_and_finish(&self) -> (Point2, Point2) {
+        let start = *self.trail_map.keys().min_by_key(|c| c.y()).unwrap();
+        let end = *self.trail_map.keys().max_by_key(|c| c.y()).unwrap();
+
+        (start, end)
+    }
+
+    // the paths next to `current` that can be walked to, a slope only goes down it
+    fn next_steps(&self, current: Point2) -> Vec<Point2> {
+        let mut next_paths: Vec<Point2> = match self.trail_map.get(&current).unwrap() {
+            Path::Normal => current.neighbours4().collect(),
+            Path::Dir(dir) => vec![current + *dir],
+        };
+
+        next_paths.retain(|coord| self.trail_map.contains_key(coord));
+        next_paths
+    }
+
+    fn longest_path_search(&self, start: Point2, end: Point2) -> u16 {
+        assert!(start < end);
+
+        self.recursive_search(1, start, start + Dir4::Down, vec![], end)
+            .unwrap()
+    }
+
+    fn recursive_search(
+        &self,
+        mut total: u16,
+        mut came_from: Point2,
+        mut current: Point2,
+        mut visited_intersections: Vec<Point2>,
+        end_coord: Point2,
+    ) -> Option<u16> {
+        let multiple_paths: Vec<Point2>;
+        loop {
+            if current == end_coord {
+                return Some(total);
+            }
+            let mut all_next_paths = self.next_steps(current);
+            all_next_paths.retain(|&coord| coord != came_from);
+
+            match all_next_paths.as_slice() {
+                [] => return None,
+                // normal path
+                [next_coord] => {
+                    total += 1;
+                    came_from = current;
+                    current = *next_coord;
+                    continue;
+                }
+
+                // intersection
+                _multiple => {
+                    multiple_paths = all_next_paths;
+                    break;
+                }
+            }
+        }
+
+        if visited_intersections.contains(&current) {
+            None
+        } else {
+            visited_intersections.push(current);
+            multiple_paths
+                .par_iter()
+                .flat_map(|next_coord| {
+                    self.recursive_search(
+                        total + 1,
+                        current,
+                        *next_coord,
+                        visited_intersections.clone(),
+                        end_coord,
+                    )
+                })
+                .max()
+        }
+    }
+}
+
+fn longest_hike(trails: &Trails) -> u32 {
+    let (start, end) = trails.get_start_and_finish();
+    trails.longest_path_search(start, end) as u32
+}
+
+// the hike goes from the only path in the top row down the slopes to the only path in the bottom row
+fn check_hike(input: &[String], trails: &Trails) -> ParseResult<()> {
+    let grid = Grid::parse(input, "#.^>v<", |c| c)?;
+    let bottom = grid.height() - 1;
+    if bottom == 0 {
+        return Err(ParseError::new(1, 0, "a second row", "end of input"));
+    }
+
+    for y in [0, bottom] {
+        if grid.row(y).iter().filter(|&&c| c != '#').count() != 1 {
+            return Err(ParseError::line(y, &input[y], "a row with a single path"));
+        }
+    }
+
+    let (start, end) = trails.get_start_and_finish();
+    if !search::bfs([start], |&pos| trails.next_steps(pos)).contains_key(&end) {
+        return Err(ParseError::line(
+            bottom,
+            &input[bottom],
+            "a path that can be reached from the top row",
+        ));
+    }
+
+    Ok(())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Trails, Trails);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        let sloped = Trails::from_string_1(input)?;
+        check_hike(input, &sloped)?;
+
+        Ok((sloped, Trails::from_string_2(input)?))
+    }
+
+    fn part_1((trails, _): &Self::Input) -> u32 {
+        longest_hike(trails)
+    }
+
+    fn part_2((_, trails): &Self::Input) -> u32 {
+        longest_hike(trails)
+    }
+}
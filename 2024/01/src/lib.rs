@@ -0,0 +1,68 @@
+/*
+we have 2 lists, we need to take the difference between the smallest on the first list to the smallest on the second and add up the difference
+
+part 2:
+we have similarty scores that are calculated:
+left num * how many times the num appears in right list
+and add those together
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution};
+
+fn get_list_distance_1(left_list: &[u64], right_list: &[u64]) -> u64 {
+    let mut left_list = left_list.to_vec();
+    let mut right_list = right_list.to_vec();
+
+    left_list.sort();
+    right_list.sort();
+
+    left_list
+        .iter()
+        .zip(right_list.iter())
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum()
+}
+
+fn get_list_similarity_2(left_list: &[u64], right_list: &[u64]) -> u64 {
+    left_list
+        .iter()
+        .map(|left_num| {
+            let num_count = right_list.iter().filter(|right| *right == left_num).count();
+            num_count as u64 * left_num
+        })
+        .sum()
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(Vec<u64>, Vec<u64>)> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+
+    for (i, line) in input.iter().enumerate() {
+        let (left, right) = parse::split_once(i, line, line, " ")?;
+        left_list.push(parse::number(i, line, left.trim())?);
+        right_list.push(parse::number(i, line, right.trim())?);
+    }
+
+    Ok((left_list, right_list))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<u64>, Vec<u64>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((left_list, right_list): &Self::Input) -> u64 {
+        get_list_distance_1(left_list, right_list)
+    }
+
+    fn part_2((left_list, right_list): &Self::Input) -> u64 {
+        get_list_similarity_2(left_list, right_list)
+    }
+}
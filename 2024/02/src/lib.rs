@@ -0,0 +1,101 @@
+/*
+part 1:
+we have reports with levels of numbers separated by spaces
+example:
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+
+this would be six reports containing 5 levels
+levels are safe if they are ascending or decending in order and the numbers near each other differ by at least 1 and at most 3
+
+we return the num of safe reports
+
+part 2:
+
+reactors tolerate a single bad level for report to be safe
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+fn check_level_safety_1(reports: &[Vec<u32>]) -> u32 {
+    let mut safe_count = 0;
+
+    for report in reports {
+        if check_report_safety(report.clone()) {
+            safe_count += 1;
+        }
+    }
+
+    safe_count
+}
+
+fn check_level_safety_2(reports: &[Vec<u32>]) -> u32 {
+    let mut safe_count = 0;
+
+    for report in reports {
+        let mut is_safe = false;
+        for removed_level in 0..report.len() {
+            let mut modified_report = report.clone();
+            modified_report.remove(removed_level);
+
+            is_safe |= check_report_safety(modified_report);
+        }
+
+        if is_safe {
+            safe_count += 1;
+        }
+    }
+
+    safe_count
+}
+
+fn check_report_safety(report: Vec<u32>) -> bool {
+    let desc = report.windows(2).all(|r| r[0] > r[1]);
+    let asc = report.windows(2).all(|r| r[0] < r[1]);
+
+    let gradual = report
+        .windows(2)
+        .all(|r| (1..=3).contains(&r[0].abs_diff(r[1])));
+
+    (desc || asc) && gradual
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<Vec<u32>>> {
+    let mut levels = vec![];
+    for (i, line) in input.iter().enumerate() {
+        let new_report: Vec<u32> = parse::numbers(i, line, line)?;
+        if new_report.len() < 2 {
+            return Err(ParseError::line(i, line, "a report of at least 2 levels"));
+        }
+
+        levels.push(new_report);
+    }
+
+    Ok(levels)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        check_level_safety_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        check_level_safety_2(input)
+    }
+}
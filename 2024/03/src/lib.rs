@@ -0,0 +1,82 @@
+/*
+part 1:
+we have to parse an input to find all the mul(x,y)
+then do the multiplications and add the total
+
+part 2:
+we have additional instructions,
+do() enables future multiplications
+and don't() disables future multiplications
+
+we have multiplications enabled, until we hit a don't() then we ignore them until we hit a do()
+
+*/
+
+use aoc_common::{ParseResult, Solution};
+use regex::Regex;
+
+fn find_multiply_sum(parsed: &[(u32, u32)]) -> u32 {
+    parsed.iter().map(|nums| nums.0 * nums.1).sum()
+}
+
+fn parse_input_1(input: &[String]) -> Vec<(u32, u32)> {
+    let text = input.join("");
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+    let mut nums = vec![];
+
+    for captures in re.captures_iter(&text) {
+        let num_1 = captures[1].parse::<u32>().unwrap();
+        let num_2 = captures[2].parse::<u32>().unwrap();
+
+        nums.push((num_1, num_2));
+    }
+
+    nums
+}
+
+fn parse_input_2(input: &[String]) -> Vec<(u32, u32)> {
+    let text = input.join("");
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don\'t\(\)").unwrap();
+
+    let mut nums = vec![];
+    let mut multiply = true;
+
+    for captures in re.captures_iter(&text) {
+        match &captures[0] {
+            "do()" => multiply = true,
+            "don't()" => multiply = false,
+            _ => {
+                if multiply {
+                    let num_1 = captures[1].parse::<u32>().unwrap();
+                    let num_2 = captures[2].parse::<u32>().unwrap();
+                    nums.push((num_1, num_2));
+                }
+            }
+        }
+    }
+
+    nums
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<(u32, u32)>, Vec<(u32, u32)>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    // the memory is corrupted on purpose, so anything that is not an instruction is skipped
+    // rather than reported, and every number is at most three digits so it always fits
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((parse_input_1(input), parse_input_2(input)))
+    }
+
+    fn part_1((parsed, _): &Self::Input) -> u32 {
+        find_multiply_sum(parsed)
+    }
+
+    fn part_2((_, parsed): &Self::Input) -> u32 {
+        find_multiply_sum(parsed)
+    }
+}
@@ -0,0 +1,78 @@
+/*
+part 1:
+we have a grid of letters we have to find how many times the word XMAS appears on it
+part 2:
+
+We now instead have to find the word X-MAS, where we have 2 MAS shaped in a X shape overlapping eachother
+
+*/
+
+use aoc_common::{Grid, ParseResult, Solution};
+
+pub struct WordGrid(Grid<char>);
+
+impl WordGrid {
+    fn from_strings(input: &[String]) -> ParseResult<Self> {
+        Ok(WordGrid(Grid::parse(input, "XMAS", |c| c)?))
+    }
+
+    fn get_xmas_count(&self) -> u32 {
+        let grid = &self.0;
+        let to_line = |line: &mut dyn Iterator<Item = (usize, usize)>| -> String {
+            line.map(|pos| grid[pos]).collect()
+        };
+
+        let mut lines: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        lines.extend(grid.columns().map(|column| column.collect::<String>()));
+        lines.extend(grid.diagonals().map(|mut diag| to_line(&mut diag)));
+        lines.extend(grid.anti_diagonals().map(|mut diag| to_line(&mut diag)));
+
+        // XMAS can't overlap with itself, only with SAMX
+        lines
+            .iter()
+            .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+            .sum::<usize>() as u32
+    }
+
+    fn search_grid_for_2mas(&self) -> u32 {
+        let grid = &self.0;
+        let is_mas = |a: (usize, usize), b: (usize, usize)| {
+            matches!((grid[a], grid[b]), ('M', 'S') | ('S', 'M'))
+        };
+
+        grid.find_all(&'A')
+            .filter(|&(x, y)| x > 0 && y > 0 && x + 1 < grid.width() && y + 1 < grid.height())
+            .filter(|&(x, y)| {
+                is_mas((x - 1, y - 1), (x + 1, y + 1)) && is_mas((x + 1, y - 1), (x - 1, y + 1))
+            })
+            .count() as u32
+    }
+}
+
+fn xmas_count_1(word_grid: &WordGrid) -> u32 {
+    word_grid.get_xmas_count()
+}
+
+fn x_mas_count_2(word_grid: &WordGrid) -> u32 {
+    word_grid.search_grid_for_2mas()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = WordGrid;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        WordGrid::from_strings(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        xmas_count_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        x_mas_count_2(input)
+    }
+}
@@ -0,0 +1,146 @@
+/*
+part 1:
+we have page number ordering rules for example:
+24 | 32 means that 24 always comes before 32, but not necessarily right before 32
+and we have updates that are sets of numbers:
+75,47,61,53,29
+and we have to see if the sets of numbers are in correct orders according to the update rules
+
+and then we add up the middle numbers of the correct updates and return the sum as an answer
+part 2:
+
+we instead have to take the incorrectly ordered update sets and order them correctly, then take the middle number from those and add those up
+*/
+
+use aoc_common::{input, parse};
+use aoc_common::{Graph, ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct OrderingRule {
+    before: u32,
+    after: u32,
+}
+
+impl OrderingRule {
+    fn from_string(line: usize, input: &str) -> ParseResult<Self> {
+        let (a, b) = parse::split_once(line, input, input, "|")?;
+        Ok(OrderingRule {
+            before: parse::number(line, input, a)?,
+            after: parse::number(line, input, b)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateBatch {
+    batch: Vec<u32>,
+    sorted: Vec<u32>,
+}
+
+impl UpdateBatch {
+    // all the rules together can loop, but the ones between the pages of an update have to order them
+    fn from_string(line: usize, input: &str, ordering_rules: &[OrderingRule]) -> ParseResult<Self> {
+        let nums: Vec<u32> = input
+            .split(",")
+            .map(|s| parse::number(line, input, s))
+            .collect::<ParseResult<_>>()?;
+        let sorted = sorted_update(ordering_rules, &nums).map_err(|cycle| {
+            let expected = format!("pages that the rules can order, they loop through {cycle:?}");
+            ParseError::line(line, input, expected)
+        })?;
+
+        Ok(UpdateBatch {
+            batch: nums,
+            sorted,
+        })
+    }
+}
+
+/// The pages of the update in the order the rules between them ask for, or the pages the rules
+/// loop through
+fn sorted_update(ordering_rules: &[OrderingRule], pages: &[u32]) -> Result<Vec<u32>, Vec<u32>> {
+    let mut rules = Graph::new();
+    for &page in pages {
+        rules.add_node(page);
+    }
+
+    for rule in ordering_rules {
+        if pages.contains(&rule.before) && pages.contains(&rule.after) {
+            rules.add_edge(rule.before, rule.after);
+        }
+    }
+
+    let names = |ids: Vec<usize>| ids.into_iter().map(|id| *rules.name(id)).collect();
+    rules.topological_sort().map(names).map_err(names)
+}
+
+fn get_update_sum_1(updates: &[UpdateBatch]) -> u32 {
+    let mut middle_sum = 0;
+
+    for update in updates {
+        if update.sorted == update.batch {
+            middle_sum += update.batch[update.batch.len() / 2];
+        }
+    }
+
+    middle_sum
+}
+
+fn get_update_sum_2(updates: &[UpdateBatch]) -> u32 {
+    let mut middle_sum = 0;
+
+    for update in updates {
+        if update.sorted != update.batch {
+            middle_sum += update.sorted[update.sorted.len() / 2];
+        }
+    }
+
+    middle_sum
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<UpdateBatch>> {
+    let blocks = input::blocks(input);
+    let (Some(rules), Some(batches)) = (blocks.first(), blocks.get(1)) else {
+        return Err(ParseError::new(
+            input.len(),
+            0,
+            "ordering rules and updates separated by an empty line",
+            "end of input",
+        ));
+    };
+
+    let orderings: Vec<OrderingRule> = rules
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, s)| OrderingRule::from_string(rules.start + i, s))
+        .collect::<ParseResult<_>>()?;
+    let updates = batches
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, s)| UpdateBatch::from_string(batches.start + i, s, &orderings))
+        .collect::<ParseResult<_>>()?;
+
+    Ok(updates)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<UpdateBatch>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(updates: &Self::Input) -> u32 {
+        get_update_sum_1(updates)
+    }
+
+    fn part_2(updates: &Self::Input) -> u32 {
+        get_update_sum_2(updates)
+    }
+}
@@ -0,0 +1,182 @@
+/*
+part 1:
+we have a grid with a guard in it, the guard moves until it hits an obstacle (#) and then turns right
+the guard keeps going forward and hitting obstacles until it will go out of the grid when no more obstacles are hit
+we have to count the tiles that are covered by the guard
+
+part 2:
+
+we have to find all the positions in the guards path where we could place an obstacle and have the guard looping forever
+and return the count of the positions that would cause this to happen
+
+*/
+
+use aoc_common::{Dir4, Grid, ParseError, ParseResult, Solution};
+use std::collections::HashSet;
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Clone, Copy)]
+struct Guard {
+    pos: Coord,
+    dir: Dir4,
+}
+
+#[derive(Debug, Clone, Default)]
+struct VisitDir {
+    up: bool,
+    right: bool,
+    down: bool,
+    left: bool,
+}
+
+impl VisitDir {
+    fn mark_dir(&mut self, dir: Dir4) {
+        match dir {
+            Dir4::Up => self.up = true,
+            Dir4::Right => self.right = true,
+            Dir4::Down => self.down = true,
+            Dir4::Left => self.left = true,
+        }
+    }
+    fn is_dir_visited(&self, dir: Dir4) -> bool {
+        match dir {
+            Dir4::Up => self.up,
+            Dir4::Right => self.right,
+            Dir4::Down => self.down,
+            Dir4::Left => self.left,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GridPos {
+    obstacle: bool,
+    visited: bool,
+    visit_dirs: VisitDir,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    guard: Guard,
+    obstacles: Grid<GridPos>,
+}
+
+impl Lab {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let obstacles = Grid::parse(input, ".#^", |c| GridPos {
+            obstacle: c == '#',
+            visited: c == '^',
+            visit_dirs: VisitDir::default(),
+        })?;
+
+        let pos = obstacles
+            .iter()
+            .find(|(_, pos)| pos.visited)
+            .map(|(coord, _)| coord)
+            .ok_or_else(|| ParseError::new(input.len(), 0, "a guard '^'", "end of input"))?;
+
+        Ok(Lab {
+            guard: Guard { pos, dir: Dir4::Up },
+            obstacles,
+        })
+    }
+
+    /// The tile in front of the guard, `None` when the guard is about to walk off the map
+    fn next(&self) -> Option<Coord> {
+        self.obstacles.step(self.guard.pos, self.guard.dir)
+    }
+}
+
+fn get_visited_tiles_sum(grid: &Lab) -> u32 {
+    let mut grid = grid.clone();
+
+    while let Some(next_coord) = grid.next() {
+        let pos = &mut grid.obstacles[next_coord];
+        if pos.obstacle {
+            grid.guard.dir = grid.guard.dir.turn_right();
+        } else {
+            grid.guard.pos = next_coord;
+            pos.visited = true;
+            pos.visit_dirs.mark_dir(grid.guard.dir);
+        }
+    }
+
+    grid.obstacles
+        .cells()
+        .iter()
+        .filter(|grid_pos| grid_pos.visited)
+        .count() as u32
+}
+
+fn get_possible_loop_count(grid: &Lab) -> u32 {
+    let mut grid = grid.clone();
+    let starting_guard = grid.guard;
+    let guard_starting_coords = starting_guard.pos;
+
+    let mut looping_coords: HashSet<Coord> = HashSet::new();
+
+    while let Some(next_coord) = grid.next() {
+        if grid.obstacles[next_coord].obstacle {
+            grid.guard.dir = grid.guard.dir.turn_right();
+            continue;
+        }
+
+        if next_coord != guard_starting_coords {
+            let grid_copy = {
+                let mut grid_copy = grid.clone();
+                grid_copy.obstacles[next_coord].obstacle = true;
+                grid_copy.guard = starting_guard;
+                grid_copy
+            };
+
+            if grid_loop_found(grid_copy) {
+                looping_coords.insert(next_coord);
+            }
+        }
+
+        grid.guard.pos = next_coord;
+    }
+
+    looping_coords.len() as u32
+}
+
+fn grid_loop_found(mut copy_grid: Lab) -> bool {
+    // running out of next tiles means we have walked off the map
+    while let Some(next_coord) = copy_grid.next() {
+        let forward_pos = &mut copy_grid.obstacles[next_coord];
+        if forward_pos.obstacle {
+            copy_grid.guard.dir = copy_grid.guard.dir.turn_right();
+        } else {
+            copy_grid.guard.pos = next_coord;
+            forward_pos.visited = true;
+            if forward_pos.visit_dirs.is_dir_visited(copy_grid.guard.dir) {
+                return true;
+            } else {
+                forward_pos.visit_dirs.mark_dir(copy_grid.guard.dir);
+            }
+        }
+    }
+
+    false
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Lab;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Lab::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_visited_tiles_sum(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_possible_loop_count(input)
+    }
+}
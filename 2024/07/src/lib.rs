@@ -0,0 +1,156 @@
+/*
+part 1:
+we have a lot of equations like:
+190: 10 19
+and we have operations '+' and '*'
+we have to find if the numbers can be solved wtih the operators
+10 * 19 = 190 so this can be solved
+we talke all the equations that can be solved and we sum the answers together
+
+part 2:
+
+we get a new operator: '||' which can concatinate the number to our existing num
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+use doers::factorial_design::fullfact;
+use ndarray::{Array2, Axis};
+use rayon::prelude::*;
+
+#[derive(Debug)]
+pub struct Equation {
+    ans: u64,
+    nums: Vec<u64>,
+}
+
+impl Equation {
+    fn from_string(line: usize, input: &str) -> ParseResult<Self> {
+        let (ans, nums) = parse::split_once(line, input, input, ":")?;
+
+        let nums_vec: Vec<u64> = parse::numbers(line, input, nums)?;
+        if nums_vec.len() < 2 {
+            return Err(ParseError::at(
+                line,
+                input,
+                &input[input.len()..],
+                "another number",
+            ));
+        }
+
+        Ok(Equation {
+            ans: parse::number(line, input, ans)?,
+            nums: nums_vec,
+        })
+    }
+
+    fn generate_possible_combinations(&self, op_count: u16) -> Array2<u16> {
+        let operator_count = self.nums.len() - 1;
+        assert!(operator_count > 0);
+
+        let possible_operations = vec![op_count; operator_count];
+
+        fullfact(&possible_operations).unwrap()
+    }
+
+    fn fully_check_if_possible_1(&self) -> bool {
+        let all_possibilities = self.generate_possible_combinations(2);
+
+        for possibility in all_possibilities.axis_iter(Axis(0)) {
+            let mut total = self.nums[0];
+            for (idx_1, op) in possibility.iter().enumerate() {
+                let idx = idx_1 + 1;
+                match op {
+                    0 => total += self.nums[idx],
+                    1 => total *= self.nums[idx],
+                    _ => unreachable!(),
+                }
+            }
+            if total == self.ans {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn concatenate_number(total: u64, num: u64) -> u64 {
+        let mut num_string = total.to_string();
+        num_string += &num.to_string();
+
+        num_string.parse::<u64>().expect("num too large to be u64")
+    }
+
+    fn fully_check_if_possible_2(&self) -> bool {
+        let all_possibilities = self.generate_possible_combinations(3);
+
+        for possibility in all_possibilities.axis_iter(Axis(0)) {
+            let mut total = self.nums[0];
+            for (idx_1, op) in possibility.iter().enumerate() {
+                if total > self.ans {
+                    break;
+                }
+                let idx = idx_1 + 1;
+                match op {
+                    0 => total += self.nums[idx],
+                    1 => total *= self.nums[idx],
+                    2 => total = Equation::concatenate_number(total, self.nums[idx]),
+                    _ => unreachable!(),
+                }
+            }
+            if total == self.ans {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn valid_equation_sum_1(equations: &[Equation]) -> u64 {
+    let valid_equations: Vec<&Equation> = equations
+        .par_iter()
+        .filter(|eq| eq.fully_check_if_possible_1())
+        .collect();
+
+    let big_int_vec: Vec<u64> = valid_equations.iter().map(|eq| eq.ans).collect();
+
+    big_int_vec.iter().sum()
+}
+
+fn valid_equation_sum_2(equations: &[Equation]) -> u64 {
+    let valid_equations: Vec<&Equation> = equations
+        .par_iter()
+        .filter(|eq| eq.fully_check_if_possible_2())
+        .collect();
+
+    let big_int_vec: Vec<u64> = valid_equations.iter().map(|eq| eq.ans).collect();
+
+    big_int_vec.iter().sum()
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<Equation>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, s)| Equation::from_string(line, s))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        valid_equation_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        valid_equation_sum_2(input)
+    }
+}
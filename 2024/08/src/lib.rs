@@ -0,0 +1,208 @@
+/*
+part 1:
+We have a grid with antennas that indicate their frequence with letters (A, O, I, etc. )
+
+The signals that the antennas emmit create antinodes, but only when 2 antennas of the same
+frequency are on the same line and one of the antenna is twice as far away as the other
+
+Example of "a" antennas and antinode placements with "#":
+..........
+...#......
+..........
+....a.....
+..........
+.....a....
+..........
+......#...
+..........
+..........
+
+we have to check how many unique locations do antinodes occur in
+
+part 2:
+
+antennas now create harmonic frequencies that extend indefinitely
+
+exmple of the antinode places for T antennas:
+T....#....
+...T......
+.T....#...
+.........#
+..#.......
+..........
+...#......
+..........
+....#.....
+..........
+
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Point2, Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy)]
+struct Antenna {
+    freq: char,
+    pos: Point2,
+}
+
+#[derive(Debug, Clone)]
+pub struct AllAntenna {
+    antennas: Vec<Antenna>,
+    unique_freqs: HashSet<char>,
+    min_coord: Point2,
+    max_coord: Point2,
+}
+
+impl AllAntenna {
+    fn in_bounds(&self, pos: Point2) -> bool {
+        (self.min_coord.x()..=self.max_coord.x()).contains(&pos.x())
+            && (self.min_coord.y()..=self.max_coord.y()).contains(&pos.y())
+    }
+
+    fn get_antinodes_1(&self) -> HashSet<Point2> {
+        let mut antinode_coords: HashSet<Point2> = HashSet::new();
+
+        for current_freq in &self.unique_freqs {
+            let freq_antennas: Vec<&Antenna> = self
+                .antennas
+                .iter()
+                .filter(|a| a.freq == *current_freq)
+                .collect();
+
+            for combination in freq_antennas.into_iter().combinations(2) {
+                let antenna_1 = combination[0];
+                let antenna_2 = combination[1];
+
+                let coord_diff = antenna_1.pos - antenna_2.pos;
+
+                let antinode_pos_1 = antenna_1.pos + coord_diff;
+                let antinode_pos_2 = antenna_2.pos - coord_diff;
+
+                if self.in_bounds(antinode_pos_1) {
+                    antinode_coords.insert(antinode_pos_1);
+                }
+
+                if self.in_bounds(antinode_pos_2) {
+                    antinode_coords.insert(antinode_pos_2);
+                }
+            }
+        }
+        antinode_coords
+    }
+
+    fn get_antinodes_2(&self) -> HashSet<Point2> {
+        let mut antinode_coords: HashSet<Point2> = HashSet::new();
+
+        for current_freq in &self.unique_freqs {
+            let freq_antennas: Vec<&Antenna> = self
+                .antennas
+                .iter()
+                .filter(|a| a.freq == *current_freq)
+                .collect();
+
+            if freq_antennas.len() > 1 {
+                for antenna in &freq_antennas {
+                    antinode_coords.insert(antenna.pos);
+                }
+            }
+
+            for combination in freq_antennas.into_iter().combinations(2) {
+                let antenna_1 = combination[0];
+                let antenna_2 = combination[1];
+
+                let coord_diff = antenna_1.pos - antenna_2.pos;
+
+                let mut antinode_pos_1 = antenna_1.pos + coord_diff;
+
+                // while the position is in bounds, we keep adding to it
+                while self.in_bounds(antinode_pos_1) {
+                    antinode_coords.insert(antinode_pos_1);
+                    antinode_pos_1 += coord_diff;
+                }
+
+                let mut antinode_pos_2 = antenna_2.pos - coord_diff;
+
+                while self.in_bounds(antinode_pos_2) {
+                    antinode_coords.insert(antinode_pos_2);
+                    antinode_pos_2 -= coord_diff;
+                }
+            }
+        }
+        antinode_coords
+    }
+}
+
+fn get_antinode_count_1(all_antenna: &AllAntenna) -> u32 {
+    let antinode_pos = all_antenna.get_antinodes_1();
+
+    antinode_pos.len() as u32
+}
+
+fn get_antinode_count_2(all_antenna: &AllAntenna) -> u32 {
+    let antinode_pos = all_antenna.get_antinodes_2();
+
+    antinode_pos.len() as u32
+}
+fn parse_input(input: &[String]) -> ParseResult<AllAntenna> {
+    let mut antennas = vec![];
+    let mut frequencies = HashSet::new();
+
+    let min_coord = Point2::ORIGIN;
+
+    let max_coord = Point2::new(
+        parse::line(input, 0)?.len() as i64 - 1,
+        input.len() as i64 - 1,
+    );
+
+    for (y, y_line) in input.iter().enumerate() {
+        for (x, x_char) in y_line.chars().enumerate() {
+            if !x_char.is_ascii_alphanumeric() && x_char != '.' {
+                return Err(parse::unexpected_char(y, x, x_char, "a frequency or '.'"));
+            }
+
+            if x_char != '.' {
+                let current_coords = Point2::from((x, y));
+
+                let antenna = Antenna {
+                    freq: x_char,
+                    pos: current_coords,
+                };
+
+                frequencies.insert(x_char);
+
+                antennas.push(antenna);
+            }
+        }
+    }
+
+    Ok(AllAntenna {
+        antennas,
+        unique_freqs: frequencies,
+        min_coord,
+        max_coord,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = AllAntenna;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_antinode_count_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_antinode_count_2(input)
+    }
+}
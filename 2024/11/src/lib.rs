@@ -0,0 +1,88 @@
+/*
+part 1:
+we have a line of stones in a line with each having a number on it
+sometimes the number on the stone changes, sometimes the stone spits in 2
+the behaviors follow rules:
+-   stones with number 0 will be replaced by number 1
+-   if stone number has even number of digits, it's replaced by 2 stones and the digits are split in 2, but no leading 0, so 1000 -> 10 and 0
+-   if other rules don't apply, the stone number is multiplied by 2024
+
+we have to get the number of stones after a 25 blinks
+
+part 2:
+
+we now blink 75 times, stone numbers explode out of proportion
+*/
+
+use aoc_common::parse;
+use aoc_common::{Memo, ParseResult, Solution};
+
+pub fn stone_count(all_stones: &[u64], blink_times: u8) -> u64 {
+    let mut stone_count = 0;
+
+    let mut memo = Memo::named("blink");
+
+    for current_stone in all_stones {
+        stone_count += blink(*current_stone, blink_times, &mut memo);
+    }
+
+    stone_count
+}
+
+fn blink(current_stone: u64, blinks_left: u8, memo: &mut Memo<(u64, u8), u64>) -> u64 {
+    if blinks_left == 0 {
+        return 1;
+    }
+
+    memo.cached((current_stone, blinks_left), |memo| {
+        if current_stone == 0 {
+            blink(1, blinks_left - 1, memo)
+        } else {
+            let stone_num_str = current_stone.to_string();
+
+            if stone_num_str.len().is_multiple_of(2) {
+                let (left, right) = split_in_half(stone_num_str);
+
+                blink(left, blinks_left - 1, memo) + blink(right, blinks_left - 1, memo)
+            } else {
+                blink(current_stone * 2024, blinks_left - 1, memo)
+            }
+        }
+    })
+}
+
+fn split_in_half(mut num_str: String) -> (u64, u64) {
+    let str_len = num_str.len();
+    let right = num_str.split_off(str_len / 2);
+
+    let left_num = num_str.parse::<u64>().unwrap();
+    let right_num = right.parse::<u64>().unwrap();
+
+    (left_num, right_num)
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<u64>> {
+    let stones = parse::line(input, 0)?;
+
+    parse::numbers(0, stones, stones)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        stone_count(input, 25)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        stone_count(input, 75)
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_2024_11::{stone_count, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check("example_1.txt", stone_count(&example, 25), 55312);
+}
@@ -0,0 +1,239 @@
+/*
+part 1:
+we have square garden plot with different regions of different plants growing in it
+we need to build a fence around each of the planting regions
+and we need to know the area of the plants inside and the perimiter of the fence for the plants
+
+we have to get each area * it's perimiter and return this sum for the whole garden plot
+
+part 2:
+
+instead of counting individual side pieces of the fence the whole straing fence now counts as a single piece of fence
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Point2, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Plot {
+    plant: char,
+    flooded: bool,
+    cheched: bool,
+}
+
+#[derive(Clone)]
+pub struct Garden {
+    garden: HashMap<Point2, Plot>,
+}
+
+impl Garden {
+    fn flood_plot(&mut self, coord: Point2, prev_plant: char) -> u32 {
+        let plot = match self.garden.get_mut(&coord) {
+            Some(plot) => plot,
+            None => return 1,
+        };
+
+        if plot.plant != prev_plant {
+            return 1;
+        }
+
+        if plot.flooded {
+            return 0;
+        }
+
+        plot.flooded = true;
+
+        let plot = *plot;
+
+        coord
+            .neighbours4()
+            .map(|next| self.flood_plot(next, plot.plant))
+            .sum()
+    }
+}
+
+fn get_garden_sum_1(garden: &Garden) -> u32 {
+    let mut garden = garden.clone();
+
+    let mut plant_fence_sum = 0;
+
+    loop {
+        let coord_plot = garden
+            .garden
+            .iter()
+            .find(|(_, plot)| !plot.cheched)
+            .map(|(coord, plot)| (*coord, *plot));
+
+        match coord_plot {
+            Some((coord, plot)) => {
+                let fence_count = garden.flood_plot(coord, plot.plant);
+
+                let plant_count = garden
+                    .garden
+                    .values()
+                    .filter(|plot| plot.flooded && !plot.cheched)
+                    .count();
+
+                plant_fence_sum += fence_count * plant_count as u32;
+
+                for (_, plot) in garden.garden.iter_mut() {
+                    if plot.flooded {
+                        plot.cheched = true;
+                    }
+                }
+            }
+            None => {
+                break;
+            }
+        }
+    }
+    plant_fence_sum
+}
+
+fn get_garden_sum_2(garden: &Garden) -> u32 {
+    let mut garden = garden.clone();
+
+    let mut plant_fence_sum = 0;
+
+    loop {
+        let coord_plot = garden
+            .garden
+            .iter()
+            .find(|(_, plot)| !plot.cheched)
+            .map(|(coord, plot)| (*coord, *plot));
+
+        match coord_plot {
+            Some((coord, plot)) => {
+                garden.flood_plot(coord, plot.plant);
+
+                let flooded_coords: Vec<Point2> = garden
+                    .garden
+                    .iter()
+                    .filter(|(_, plot)| plot.flooded && !plot.cheched)
+                    .map(|(coord, _)| *coord)
+                    .collect();
+
+                let plant_count = flooded_coords.len();
+
+                let mut total_plot_corner_count: u32 = 0;
+
+                for flood_coord in &flooded_coords {
+                    let mut surrounding = [[false; 3]; 3];
+
+                    for (surroundind_y, y_diff) in (-1..=1).enumerate() {
+                        for (surroundind_x, x_diff) in (-1..=1).enumerate() {
+                            let checking_coord = *flood_coord + Point2::new(x_diff, y_diff);
+
+                            let is_surrounding = flooded_coords.contains(&checking_coord);
+
+                            surrounding[surroundind_y][surroundind_x] = is_surrounding;
+                        }
+                    }
+
+                    total_plot_corner_count += check_corner_count(surrounding) as u32
+                }
+
+                plant_fence_sum += total_plot_corner_count * plant_count as u32;
+
+                for (_, plot) in garden.garden.iter_mut() {
+                    if plot.flooded {
+                        plot.cheched = true;
+                    }
+                }
+            }
+            None => {
+                break;
+            }
+        }
+    }
+    plant_fence_sum
+}
+
+fn check_corner_count(mut array: [[bool; 3]; 3]) -> u8 {
+    let mut total = 0;
+
+    for _ in 0..4 {
+        if check_inside_corner(&array) {
+            total += 1;
+        }
+
+        if check_outside_corner(&array) {
+            total += 1;
+        }
+
+        array = rotate(array);
+    }
+
+    total
+}
+fn check_inside_corner(array: &[[bool; 3]; 3]) -> bool {
+    if array[1][0] && array[0][1] && !array[0][0] {
+        return true;
+    }
+
+    false
+}
+
+fn check_outside_corner(array: &[[bool; 3]; 3]) -> bool {
+    if !array[1][0] && !array[0][1] {
+        return true;
+    }
+
+    false
+}
+
+fn rotate(array: [[bool; 3]; 3]) -> [[bool; 3]; 3] {
+    let mut rotated = [[false; 3]; 3];
+
+    for i in 0..3 {
+        for j in 0..3 {
+            rotated[j][2 - i] = array[i][j];
+        }
+    }
+
+    rotated
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Garden> {
+    let mut garden: HashMap<Point2, Plot> = HashMap::new();
+    for (y, y_line) in input.iter().enumerate() {
+        for (x, x_char) in y_line.char_indices() {
+            if !x_char.is_ascii_uppercase() {
+                return Err(parse::unexpected_char(y, x, x_char, "a plant letter"));
+            }
+
+            let current_coord = Point2::from((x, y));
+            let new_plot = Plot {
+                plant: x_char,
+                flooded: false,
+                cheched: false,
+            };
+
+            garden.insert(current_coord, new_plot);
+        }
+    }
+
+    Ok(Garden { garden })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Garden;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        get_garden_sum_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        get_garden_sum_2(input)
+    }
+}
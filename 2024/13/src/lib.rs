@@ -0,0 +1,150 @@
+/*
+part 1:
+we have a claw machine, we control the button with 2 buttons.
+it costs 3 tokes to push the A button and 1 token to push the B button (typical EA game)
+the buttons move the claw to the right and forward in the y axis
+
+we have to figure out what is the smallest amount of tokens to get to a certain X and Y position
+example:
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+part 2:
+
+we had a unit conversion error and all the prices are actually 10000000000000 units higher than they are claimed to be
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+use ndarray::{arr1, arr2, stack, Array1, Array2, Axis};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    a: Array1<f64>,
+    b: Array1<f64>,
+    target: Array1<f64>,
+}
+
+impl ClawMachine {
+    // the machine takes up three lines starting from `start`
+    fn from_string(
+        input: &[String],
+        start: usize,
+        add_on_num: f64,
+        regex_string: &[&Regex],
+    ) -> ParseResult<Self> {
+        let a = ClawMachine::capture(input, start, regex_string[0], "Button A")?;
+        let b = ClawMachine::capture(input, start + 1, regex_string[0], "Button B")?;
+        let target = ClawMachine::capture(input, start + 2, regex_string[1], "Prize")?;
+
+        Ok(ClawMachine {
+            a: arr1(&a),
+            b: arr1(&b),
+            target: arr1(&[target[0] + add_on_num, target[1] + add_on_num]),
+        })
+    }
+
+    fn capture(input: &[String], line: usize, regex: &Regex, name: &str) -> ParseResult<[f64; 2]> {
+        let text = parse::line(input, line)?;
+        let captures = regex
+            .captures(text)
+            .filter(|captures| captures[0].starts_with(name))
+            .ok_or_else(|| ParseError::line(line, text, format!("\"{name}: ...\"")))?;
+
+        Ok([
+            parse::number(line, text, captures.get(1).unwrap().as_str())?,
+            parse::number(line, text, captures.get(2).unwrap().as_str())?,
+        ])
+    }
+
+    fn solve_machine(&self) -> Option<u64> {
+        // [A B] * [X Y] = [Xn Yn]
+        // [A B] = [Xn Yn] * [X Y].inv()
+
+        let multiply: Array1<f64> = arr1(&[3.0, 1.0]);
+        let matrix: Array2<f64> = stack![Axis(0), self.a, self.b];
+
+        match inverse_2x2(&matrix) {
+            Some(inverse) => {
+                let product = self.target.dot(&inverse);
+
+                if !product.iter().all(|&val| (val - val.round()).abs() < 0.001) {
+                    return None;
+                }
+
+                let result = product.dot(&multiply);
+
+                Some(result.round() as u64)
+            }
+            None => None,
+        }
+    }
+}
+
+fn inverse_2x2(matrix: &Array2<f64>) -> Option<Array2<f64>> {
+    let [a, b, c, d] = [
+        matrix[[0, 0]],
+        matrix[[0, 1]],
+        matrix[[1, 0]],
+        matrix[[1, 1]],
+    ];
+
+    let determinant = a * d - b * c;
+    if determinant == 0.0 {
+        return None;
+    }
+
+    Some(arr2(&[[d, -b], [-c, a]]) / determinant)
+}
+
+fn button_press_cost(claw_machines: &[ClawMachine]) -> u64 {
+    claw_machines
+        .iter()
+        .filter_map(ClawMachine::solve_machine)
+        .sum()
+}
+
+fn parse_input(input: &[String], add_on_num: f64) -> ParseResult<Vec<ClawMachine>> {
+    let mut claw_machines = vec![];
+
+    let button_regex = Regex::new(r"^Button [AB]: X\+(\d+), Y\+(\d+)$").unwrap();
+    let target_regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+
+    // every machine is three lines followed by an empty line
+    for start in (0..input.len()).step_by(4) {
+        claw_machines.push(ClawMachine::from_string(
+            input,
+            start,
+            add_on_num,
+            &[&button_regex, &target_regex],
+        )?);
+    }
+
+    Ok(claw_machines)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<ClawMachine>, Vec<ClawMachine>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((
+            parse_input(input, 0.0)?,
+            parse_input(input, 10000000000000.0)?,
+        ))
+    }
+
+    fn part_1((claw_machines, _): &Self::Input) -> u64 {
+        button_press_cost(claw_machines)
+    }
+
+    fn part_2((_, claw_machines): &Self::Input) -> u64 {
+        button_press_cost(claw_machines)
+    }
+}
@@ -0,0 +1,134 @@
+/*
+part 1:
+we have a lobby of robots with position and velocity, the robots are constantly running around in the lobby
+we have to predict where the robots will be in 100 steps, count their positions in each quadrant of the lobby
+and then we multiply the count of the robots in each quadrant
+
+part 2:
+
+*/
+
+use aoc_common::{math, parse};
+use aoc_common::{ParseError, ParseResult, Point2, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RobotPath {
+    pos: Point2,
+    velocity: Point2,
+}
+
+impl RobotPath {
+    fn from_string(line: usize, input: &str) -> ParseResult<Self> {
+        let parse_regex = Regex::new(r"^p=(\d+),(\d+) v=(\-?\d+),(\-?\d+)$").unwrap();
+
+        let caps = parse_regex
+            .captures(input)
+            .ok_or_else(|| ParseError::line(line, input, "\"p=0,4 v=3,-3\""))?;
+        let num = |i: usize| parse::number(line, input, caps.get(i).unwrap().as_str());
+
+        Ok(RobotPath {
+            pos: Point2::new(num(1)?, num(2)?),
+            velocity: Point2::new(num(3)?, num(4)?),
+        })
+    }
+    fn step_forward(&mut self, times: u32, max_coords: Point2) {
+        let step_coords = self.pos + self.velocity * times as i64;
+
+        self.pos = Point2::new(
+            step_coords.x().rem_euclid(max_coords.x()),
+            step_coords.y().rem_euclid(max_coords.y()),
+        );
+    }
+}
+
+pub fn robot_safety_factor_1(paths: &[RobotPath], max_coords: Point2) -> u32 {
+    let mut paths = paths.to_vec();
+
+    paths
+        .iter_mut()
+        .for_each(|r| r.step_forward(100, max_coords));
+
+    get_safety_factor(&paths, &max_coords)
+}
+
+fn get_safety_factor(robots: &Vec<RobotPath>, max_coords: &Point2) -> u32 {
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+
+    let middle_x = max_coords.x() / 2;
+    let middle_y = max_coords.y() / 2;
+    for robot in robots {
+        let pos = robot.pos;
+
+        if pos.x() == middle_x || pos.y() == middle_y {
+            continue;
+        }
+
+        if pos.y() < middle_y {
+            if pos.x() < middle_x {
+                q1 += 1;
+            } else {
+                q2 += 1;
+            }
+        } else if pos.x() < middle_x {
+            q3 += 1;
+        } else {
+            q4 += 1;
+        }
+    }
+
+    q1 * q2 * q3 * q4
+}
+
+// the first second the robots draw the tree, `None` when they never do
+fn time_till_easter_egg(paths: &[RobotPath], max_coords: Point2) -> Option<u32> {
+    let mut paths = paths.to_vec();
+
+    // every robot wraps around the lobby, so all the positions repeat after lcm(width, height)
+    let repeats = math::lcm(max_coords.x() as u64, max_coords.y() as u64) as u32;
+
+    for step in 0..repeats {
+        // the robots that draw the tree bunch up in one quadrant, which brings the factor down
+        if get_safety_factor(&paths, &max_coords) < 170_000_000 {
+            return Some(step);
+        }
+
+        paths.iter_mut().for_each(|r| r.step_forward(1, max_coords));
+    }
+
+    None
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<RobotPath>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, s)| RobotPath::from_string(line, s))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<RobotPath>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        robot_safety_factor_1(input, Point2::new(101, 103))
+    }
+
+    // nothing to answer when the robots never draw the tree
+    fn part_2(input: &Self::Input) -> String {
+        time_till_easter_egg(input, Point2::new(101, 103))
+            .map(|step| step.to_string())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_2024_14::{robot_safety_factor_1, Day};
+use aoc_common::{harness, Point2};
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check(
+        "example_1.txt",
+        robot_safety_factor_1(&example, Point2::new(11, 7)),
+        12,
+    );
+}
@@ -0,0 +1,209 @@
+/*
+part 1:
+we have a warehouse full of robots that move.
+we know the robot movements but the robot movements fail sometimes if the box or the robot is blocked by the wall
+
+we have a long set of moves that the robot will move according to and see what state the warehouse will end up in
+
+the answer is the box GPS coordinates, 100 points per the coords from the top of the board and point per the coords from the left of the warehouse
+
+sum up all the box coordinate points and return the answer
+
+part 2:
+
+all the boxes are expanded to be 2 parts and the coordinates are stretched horisontally
+
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{Dir4, Grid, ParseError, ParseResult, Solution};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Box,
+    BoxL,
+    BoxR,
+}
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    robot: Coord,
+    boxes: Grid<Option<Tile>>,
+    moves: VecDeque<Dir4>,
+}
+
+impl Warehouse {
+    fn from_string(input: &[String], expand: bool) -> ParseResult<Self> {
+        let mut warehouse_lines = vec![];
+        let mut moves = VecDeque::new();
+
+        let mut warehouse_part = true;
+
+        for (i, line) in input.iter().enumerate() {
+            if line.is_empty() {
+                warehouse_part = false;
+                continue;
+            }
+
+            let allowed = if warehouse_part { "#.O@" } else { "^>v<" };
+            if let Some((column, c)) = line.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+                return Err(parse::unexpected_char(
+                    i,
+                    column,
+                    c,
+                    &format!("one of {allowed:?}"),
+                ));
+            }
+
+            let mut line = line.clone();
+            if warehouse_part {
+                if expand {
+                    line = line
+                        .replace("#", "##")
+                        .replace("O", "[]")
+                        .replace(".", "..")
+                        .replace("@", "@.")
+                }
+                warehouse_lines.push(line);
+            } else {
+                moves.extend(line.chars().filter_map(Dir4::from_arrow));
+            }
+        }
+
+        let tiles = Grid::parse(&warehouse_lines, "#.O@[]", |c| c)?;
+        let robot = tiles.find(&'@').ok_or_else(|| {
+            ParseError::new(warehouse_lines.len(), 0, "a robot '@'", "end of warehouse")
+        })?;
+
+        let boxes = tiles.map(|tile| match tile {
+            '#' => Some(Tile::Wall),
+            '[' => Some(Tile::BoxL),
+            ']' => Some(Tile::BoxR),
+            'O' => Some(Tile::Box),
+            _ => None,
+        });
+
+        Ok(Warehouse {
+            robot,
+            boxes,
+            moves,
+        })
+    }
+
+    fn arrange_warehouse(&mut self) {
+        while let Some(offset) = self.moves.pop_front() {
+            let Some(next_coord) = self.boxes.step(self.robot, offset) else {
+                continue;
+            };
+
+            let mut movable_tiles = HashSet::new();
+
+            let moved = self.search_boxes(next_coord, offset, &mut movable_tiles);
+
+            if moved {
+                // take all the boxes that are meant to be moved out of the grid
+                let coord_boxes: Vec<(Coord, Tile)> = movable_tiles
+                    .into_iter()
+                    .map(|coord| (coord, self.boxes[coord].take().unwrap()))
+                    .collect();
+
+                // and put them back one step further in the direction we are moving to
+                for (coord, tile) in coord_boxes {
+                    let moved_coord = self.boxes.step(coord, offset).unwrap();
+                    self.boxes[moved_coord] = Some(tile);
+                }
+
+                // move robot
+                self.boxes[next_coord] = None;
+                self.robot = next_coord;
+            }
+        }
+    }
+
+    fn search_boxes(
+        &self,
+        curr_coord: Coord,
+        offset: Dir4,
+        box_coords: &mut HashSet<Coord>,
+    ) -> bool {
+        // searches the tile `step` away from this one, off the grid we cannot move to it
+        let search_next = |step: Dir4, box_coords: &mut HashSet<Coord>| {
+            self.boxes
+                .step(curr_coord, step)
+                .is_some_and(|next_coord| self.search_boxes(next_coord, offset, box_coords))
+        };
+
+        match &self.boxes[curr_coord] {
+            None => {
+                true // empty coord, can move here
+            }
+
+            Some(Tile::Wall) => {
+                false // wall, cannot move here
+            }
+
+            Some(box_tile) if box_coords.insert(curr_coord) => {
+                // new tile, not previously visited
+                match box_tile {
+                    Tile::Box => search_next(offset, box_coords),
+                    Tile::BoxL => {
+                        search_next(offset, box_coords) && search_next(Dir4::Right, box_coords)
+                    }
+                    Tile::BoxR => {
+                        search_next(offset, box_coords) && search_next(Dir4::Left, box_coords)
+                    }
+
+                    Tile::Wall => unreachable!(),
+                }
+            }
+
+            Some(_) => {
+                true // tile already checked
+            }
+        }
+    }
+
+    fn get_box_sum(&self) -> u32 {
+        self.boxes
+            .iter()
+            .filter(|(_, val)| **val == Some(Tile::Box) || **val == Some(Tile::BoxL))
+            .map(|((x, y), _)| (y * 100 + x) as u32)
+            .sum()
+    }
+}
+
+fn warehouse_box_sum(warehouse: &Warehouse) -> u32 {
+    let mut warehouse = warehouse.clone();
+
+    warehouse.arrange_warehouse();
+
+    warehouse.get_box_sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Warehouse, Warehouse);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((
+            Warehouse::from_string(input, false)?,
+            Warehouse::from_string(input, true)?,
+        ))
+    }
+
+    fn part_1((warehouse, _): &Self::Input) -> u32 {
+        warehouse_box_sum(warehouse)
+    }
+
+    fn part_2((_, warehouse): &Self::Input) -> u32 {
+        warehouse_box_sum(warehouse)
+    }
+}
@@ -0,0 +1,161 @@
+/*
+part 1:
+we have a maze with reindeer in it. The reindeer start from S corner and they go to the E corner and the point is to go from corner
+to corner with the least steps possible
+
+going forward increases the score by 1 but turning increases the score by 1000
+
+we have to find the smallest score possible and return that
+
+part 2:
+
+we have to find all the paths that have the lowest score to get to the end coord
+and then we have to find the count of unique coords visited in these paths
+and return the count of the coords
+
+*/
+
+use aoc_common::{parse, search};
+use aoc_common::{Dir4, Grid, ParseError, ParseResult, Point2, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+struct Reindeer {
+    current_node: Point2,
+    facing_dir: Dir4,
+}
+
+#[derive(Clone)]
+pub struct NodeGraph {
+    start_coord: Point2,
+    end_coord: Point2,
+    open_tiles: HashSet<Point2>,
+}
+
+impl NodeGraph {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let grid = Grid::parse(input, ".#SE", |c| c)?;
+        let start_coord = find_single(&grid, input, 'S', "a single start tile 'S'")?;
+        let end_coord = find_single(&grid, input, 'E', "a single end tile 'E'")?;
+        let mut open_tiles: HashSet<Point2> = HashSet::new();
+        for (y, y_line) in input.iter().enumerate() {
+            for (x, x_char) in y_line.char_indices() {
+                let current_coord = Point2::from((x, y));
+                match x_char {
+                    '.' | 'S' | 'E' => {
+                        open_tiles.insert(current_coord);
+                    }
+                    '#' => {}
+                    _ => return Err(parse::unexpected_char(y, x, x_char, "'.', '#', 'S' or 'E'")),
+                }
+            }
+        }
+
+        let reachable = search::bfs([start_coord], |&coord| {
+            coord
+                .neighbours4()
+                .filter(|next| open_tiles.contains(next))
+                .collect::<Vec<_>>()
+        });
+        if !reachable.contains_key(&end_coord) {
+            let (x, y) = (end_coord.x() as usize, end_coord.y() as usize);
+            return Err(ParseError::at(
+                y,
+                &input[y],
+                &input[y][x..=x],
+                "an end tile that can be reached from the start",
+            ));
+        }
+
+        Ok(NodeGraph {
+            start_coord,
+            end_coord,
+            open_tiles,
+        })
+    }
+
+    fn start(&self) -> Reindeer {
+        Reindeer {
+            current_node: self.start_coord,
+            facing_dir: Dir4::Right,
+        }
+    }
+
+    /// Walking forward costs 1, turning left or right and taking a step that way costs 1001
+    fn moves(&self, reindeer: Reindeer) -> impl Iterator<Item = (Reindeer, u64)> + '_ {
+        let dir = reindeer.facing_dir;
+        [(dir, 1), (dir.turn_left(), 1001), (dir.turn_right(), 1001)]
+            .into_iter()
+            .map(move |(facing_dir, cost)| {
+                let current_node = reindeer.current_node + facing_dir;
+                let next = Reindeer {
+                    current_node,
+                    facing_dir,
+                };
+                (next, cost)
+            })
+            .filter(|(next, _)| self.open_tiles.contains(&next.current_node))
+    }
+}
+
+fn find_single(
+    grid: &Grid<char>,
+    input: &[String],
+    tile: char,
+    expected: &str,
+) -> ParseResult<Point2> {
+    let mut found = grid.find_all(&tile);
+    match (found.next(), found.next()) {
+        (Some(pos), None) => Ok(Point2::from(pos)),
+        (None, _) => Err(ParseError::new(input.len(), 0, expected, "end of input")),
+        (Some(_), Some((x, y))) => Err(ParseError::at(y, &input[y], &input[y][x..=x], expected)),
+    }
+}
+
+fn find_lowest_path(node_graph: &NodeGraph) -> u32 {
+    let path = search::dijkstra(
+        [node_graph.start()],
+        |&reindeer| node_graph.moves(reindeer),
+        |reindeer| reindeer.current_node == node_graph.end_coord,
+    )
+    .unwrap();
+
+    path.cost as u32
+}
+
+fn find_lowest_path_node_counts(node_graph: &NodeGraph) -> u32 {
+    let lowest_paths = search::all_shortest_paths(
+        [node_graph.start()],
+        |&reindeer| node_graph.moves(reindeer),
+        |reindeer| reindeer.current_node == node_graph.end_coord,
+    )
+    .unwrap();
+
+    let unique_coords_visited: HashSet<Point2> = lowest_paths
+        .states()
+        .into_iter()
+        .map(|reindeer| reindeer.current_node)
+        .collect();
+
+    unique_coords_visited.len() as u32
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = NodeGraph;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        NodeGraph::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        find_lowest_path(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        find_lowest_path_node_counts(input)
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_2024_17::Day;
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", "4,6,3,5,6,3,5,2,1,0".to_string());
+}
+
+#[test]
+fn part_2() {
+    harness::example_2::<Day>("example_2.txt", 117440);
+}
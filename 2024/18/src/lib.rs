@@ -0,0 +1,131 @@
+/*
+part 1:
+we have a 2 dimensional that is 71 x 71 blocks grid with falling bytes
+we have to find the exit when 1024 byts have fallen onto the grid
+
+part 2:
+
+we have to see how many blocks can fall until all paths are blocked and we have to return the coords for the final block
+that blocks the path
+
+*/
+
+use aoc_common::{parse, search};
+use aoc_common::{Grid, ParseError, ParseResult, Point2, Solution};
+
+const EXIT: Point2 = Point2::new(70, 70);
+const FALLEN_BYTES: usize = 1024;
+
+/// The tiles up to `end_coord`, `true` where a byte fell
+fn fallen_grid(byte_pos: &[Point2], end_coord: Point2) -> Grid<bool> {
+    let mut fallen = Grid::new(
+        end_coord.x() as usize + 1,
+        end_coord.y() as usize + 1,
+        false,
+    );
+
+    for byte in byte_pos {
+        if let Some(tile) = byte.pos().and_then(|pos| fallen.get_mut(pos)) {
+            *tile = true;
+        }
+    }
+
+    fallen
+}
+
+pub fn min_steps_till_exit(bytes: &[Point2], byte_count: usize, end_coord: Point2) -> Option<u32> {
+    let fallen = &fallen_grid(&bytes[..byte_count.min(bytes.len())], end_coord);
+    let end = end_coord.pos();
+
+    let path = search::dijkstra(
+        [(0, 0)],
+        |&pos| {
+            fallen
+                .neighbours4(pos)
+                .filter(move |&next| !fallen[next])
+                .map(|next| (next, 1))
+        },
+        |&pos| Some(pos) == end,
+    )?;
+
+    Some(path.cost as u32)
+}
+
+/// The first byte after which the exit can't be reached, `None` when it still can after every byte
+pub fn first_byte_to_block_exit(bytes: &[Point2], end_coord: Point2) -> Option<String> {
+    if min_steps_till_exit(bytes, bytes.len(), end_coord).is_some() {
+        return None;
+    }
+
+    let mut min = 1;
+    let mut max = bytes.len();
+    while min != max {
+        let middle = (min + max) / 2;
+        // count
+        if min_steps_till_exit(bytes, middle, end_coord).is_none() {
+            max = middle;
+        } else {
+            min = middle + 1;
+        }
+    }
+
+    let blocking_byte = bytes[min - 1];
+    Some(format!("{},{}", blocking_byte.x(), blocking_byte.y()))
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<Point2>> {
+    let mut bytes = vec![];
+    for (line, b) in input.iter().enumerate() {
+        let (x_str, y_str) = parse::split_once(line, b, b, ",")?;
+        let byte = Point2::new(
+            parse::number(line, b, x_str)?,
+            parse::number(line, b, y_str)?,
+        );
+        if !(0..=EXIT.x()).contains(&byte.x()) || !(0..=EXIT.y()).contains(&byte.y()) {
+            return Err(ParseError::line(
+                line,
+                b,
+                "a position inside the memory space",
+            ));
+        }
+
+        bytes.push(byte);
+    }
+
+    if bytes.is_empty() {
+        return Err(ParseError::new(0, 0, "a byte position", "end of input"));
+    }
+
+    if min_steps_till_exit(&bytes, FALLEN_BYTES, EXIT).is_none() {
+        let line = FALLEN_BYTES.min(bytes.len()) - 1;
+        return Err(ParseError::line(
+            line,
+            &input[line],
+            "the exit to still be reachable after the first 1024 bytes",
+        ));
+    }
+
+    Ok(bytes)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Point2>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        // checked while parsing
+        min_steps_till_exit(input, FALLEN_BYTES, EXIT).unwrap()
+    }
+
+    fn part_2(input: &Self::Input) -> String {
+        // nothing to answer when no byte blocks the exit
+        first_byte_to_block_exit(input, EXIT).unwrap_or_default()
+    }
+}
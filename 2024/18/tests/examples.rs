@@ -0,0 +1,32 @@
+use aoc_2024_18::{first_byte_to_block_exit, min_steps_till_exit, Day};
+use aoc_common::{harness, Point2};
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check(
+        "example_1.txt",
+        min_steps_till_exit(&example, 12, Point2::new(6, 6)).unwrap(),
+        22,
+    );
+}
+
+#[test]
+fn part_2() {
+    let example = harness::parse_file::<Day>("example_2.txt");
+    harness::check(
+        "example_2.txt",
+        first_byte_to_block_exit(&example, Point2::new(6, 6)),
+        Some("6,1".to_string()),
+    );
+    harness::check(
+        "example_2.txt",
+        first_byte_to_block_exit(&example[..20], Point2::new(6, 6)),
+        None,
+    );
+    harness::check(
+        "no bytes",
+        first_byte_to_block_exit(&[], Point2::new(6, 6)),
+        None,
+    );
+}
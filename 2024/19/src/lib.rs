@@ -0,0 +1,162 @@
+/*
+part 1:
+we have towels and we arrange them to show designs
+every towel has a pattern of colored stripes, that can be white w blue u, black b, red r or green g.
+
+if we want a design rgrgr, we havet o get 2 towels with rg and one towel with r to make the design
+
+we have to check which designs are possible with the given towels and return the count of possible designs
+
+part 2:
+
+we have to check how many different ways the designs can be made from available towels
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{Memo, ParseError, ParseResult, Solution};
+use std::vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Color {
+    W,
+    U,
+    B,
+    R,
+    G,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Towel {
+    colors: Vec<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Design {
+    colors: Vec<Color>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TowelDesigns {
+    towels: Vec<Towel>,
+    wanted_designs: Vec<Design>,
+}
+
+impl TowelDesigns {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let towels_line = parse::line(input, 0)?;
+
+        let mut towels = vec![];
+        for towel in towels_line.split(", ") {
+            towels.push(Towel {
+                colors: TowelDesigns::colors(0, towels_line, towel)?,
+            });
+        }
+
+        let mut designs = vec![];
+        for (line, design) in input.iter().enumerate().skip(2) {
+            designs.push(Design {
+                colors: TowelDesigns::colors(line, design, design)?,
+            });
+        }
+
+        Ok(TowelDesigns {
+            towels,
+            wanted_designs: designs,
+        })
+    }
+
+    // `stripes` is a slice of `text`
+    fn colors(line: usize, text: &str, stripes: &str) -> ParseResult<Vec<Color>> {
+        let mut colors = vec![];
+        for (i, char) in stripes.char_indices() {
+            let char_color = match char {
+                'w' => Color::W,
+                'u' => Color::U,
+                'b' => Color::B,
+                'r' => Color::R,
+                'g' => Color::G,
+                _ => {
+                    let token = &stripes[i..i + char.len_utf8()];
+                    return Err(ParseError::at(
+                        line,
+                        text,
+                        token,
+                        "'w', 'u', 'b', 'r' or 'g'",
+                    ));
+                }
+            };
+
+            colors.push(char_color);
+        }
+
+        Ok(colors)
+    }
+
+    fn recursive_design_finder(
+        &self,
+        design: &[Color],
+        memo_cache: &mut Memo<Vec<Color>, u64>,
+    ) -> u64 {
+        if design.is_empty() {
+            return 1;
+        }
+
+        memo_cache.cached_ref(design, |memo_cache| {
+            let mut possible = 0;
+
+            for towel in &self.towels {
+                if towel.colors.len() <= design.len()
+                    && towel.colors == design[..towel.colors.len()]
+                {
+                    possible +=
+                        self.recursive_design_finder(&design[towel.colors.len()..], memo_cache)
+                }
+            }
+
+            possible
+        })
+    }
+}
+
+fn num_possible_designs(towel_designs: &TowelDesigns) -> u64 {
+    let mut possible = 0;
+    let mut memo_cache = Memo::named("possible designs");
+    for design in &towel_designs.wanted_designs {
+        if towel_designs.recursive_design_finder(&design.colors, &mut memo_cache) > 0 {
+            possible += 1;
+        }
+    }
+
+    possible
+}
+
+fn num_total_designs(towel_designs: &TowelDesigns) -> u64 {
+    let mut total = 0;
+    let mut memo_cache = Memo::named("total designs");
+    for design in &towel_designs.wanted_designs {
+        total += towel_designs.recursive_design_finder(&design.colors, &mut memo_cache)
+    }
+
+    total
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = TowelDesigns;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        TowelDesigns::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        num_possible_designs(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        num_total_designs(input)
+    }
+}
@@ -0,0 +1,164 @@
+/*
+part 1:
+
+we have a data racetrack and we are racing in a 3d grid from the start S to the end E
+we have empty spaces (.) and walls (#)
+each move takes a picosecond and we have to get to the end as fast as possible
+but in this race we can cheat once per race. we can walk thnough walls for 2 steps per race (so 1 wall in total)
+
+part 2:
+
+we can now skip max 20 steps
+
+*/
+
+use aoc_common::search;
+use aoc_common::{Grid, ParseError, ParseResult, Point2, Solution};
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Node {
+    min_cost_from_start: u32,
+    min_cost_to_end: u32,
+    cost_with_cheat: Vec<u32>,
+}
+
+impl Node {
+    fn new() -> Self {
+        Node {
+            min_cost_from_start: u32::MAX,
+            min_cost_to_end: u32::MAX,
+
+            cost_with_cheat: vec![],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Racetrack {
+    /// `None` for the walls
+    cost_map: Grid<Option<Node>>,
+    start_coord: Coord,
+    end_coord: Coord,
+}
+
+impl Racetrack {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let tiles = Grid::parse(input, ".#SE", |c| c)?;
+        let find = |tile: char| {
+            tiles.find(&tile).ok_or_else(|| {
+                ParseError::new(input.len(), 0, format!("a {tile:?} tile"), "end of input")
+            })
+        };
+
+        Ok(Racetrack {
+            start_coord: find('S')?,
+            end_coord: find('E')?,
+            cost_map: tiles.map(|&c| (c != '#').then(Node::new)),
+        })
+    }
+
+    fn min_steps_from_start(&mut self) {
+        self.min_steps(self.start_coord, |node| &mut node.min_cost_from_start)
+    }
+
+    fn min_steps_to_end(&mut self) {
+        self.min_steps(self.end_coord, |node| &mut node.min_cost_to_end)
+    }
+
+    /// Walks out from `from`, writing the lowest cost of reaching every tile into `cost`
+    fn min_steps(&mut self, from: Coord, cost: impl Fn(&mut Node) -> &mut u32) {
+        let track = &self.cost_map;
+        let distances = search::bfs([from], |&pos| {
+            track
+                .neighbours4(pos)
+                .filter(move |&next| track[next].is_some())
+        });
+
+        for (pos, distance) in distances {
+            *cost(self.cost_map[pos].as_mut().unwrap()) = distance as u32;
+        }
+    }
+
+    fn search_cheats(&mut self, picoseconds: u32) {
+        let copy_map = self.cost_map.clone();
+        let all_coords: Vec<Coord> = copy_map
+            .iter()
+            .filter(|(_, node)| node.is_some())
+            .map(|(coord, _)| coord)
+            .collect();
+
+        for &curr_coord in &all_coords {
+            let current_node = self.cost_map[curr_coord].as_mut().unwrap();
+
+            let coords_in_walking_dist = all_coords
+                .iter()
+                .map(|c1| (c1, Self::coord_dist(c1, &curr_coord)))
+                .filter(|(_, c1)| *c1 <= picoseconds);
+
+            for (cheat_coord, walk_distance) in coords_in_walking_dist {
+                if let Some(copy_node) = &copy_map[*cheat_coord] {
+                    let cheat_node_to_end = copy_node.min_cost_to_end;
+
+                    let cost_with_cheat =
+                        current_node.min_cost_from_start + walk_distance + cheat_node_to_end;
+
+                    current_node.cost_with_cheat.push(cost_with_cheat);
+                }
+            }
+        }
+    }
+
+    fn coord_dist(c1: &Coord, c2: &Coord) -> u32 {
+        Point2::from(*c1).manhattan(&Point2::from(*c2)) as u32
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.cost_map.cells().iter().flatten()
+    }
+}
+
+/// The cheats of at most `picoseconds` that save at least `min_saved` picoseconds
+pub fn cheats_that_save(grid: &Racetrack, picoseconds: u32, min_saved: u32) -> u32 {
+    let mut grid = grid.clone();
+
+    grid.min_steps_from_start();
+    grid.min_steps_to_end();
+
+    grid.search_cheats(picoseconds);
+
+    let max_cost = grid.nodes().map(|n| n.min_cost_from_start).max().unwrap();
+
+    let saved_costs: Vec<u32> = grid
+        .nodes()
+        .flat_map(|n| n.cost_with_cheat.clone())
+        .filter(|&cost_with_cheat| cost_with_cheat < max_cost)
+        .map(|cost| max_cost - cost)
+        .collect();
+
+    saved_costs
+        .iter()
+        .filter(|&&cost| cost >= min_saved)
+        .count() as u32
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Racetrack;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Racetrack::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        cheats_that_save(input, 2, 100)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        cheats_that_save(input, 20, 100)
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_2024_20::{cheats_that_save, Day};
+use aoc_common::harness;
+
+// no cheat in the example saves 100 picoseconds, so the counts are the ones the puzzle lists for
+// smaller savings
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    for (min_saved, cheats) in [(64, 1), (40, 2), (38, 3), (20, 5), (12, 8), (2, 44)] {
+        harness::check(
+            "example_1.txt",
+            cheats_that_save(&example, 2, min_saved),
+            cheats,
+        );
+    }
+}
+
+#[test]
+fn part_2() {
+    let example = harness::parse_file::<Day>("example_2.txt");
+    for (min_saved, cheats) in [(76, 3), (74, 7), (72, 29), (50, 285)] {
+        harness::check(
+            "example_2.txt",
+            cheats_that_save(&example, 20, min_saved),
+            cheats,
+        );
+    }
+}
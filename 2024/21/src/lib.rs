@@ -0,0 +1,243 @@
+/*
+part 1:
+we have a robot with a number pad controlled by another robot with a keypad controlled by another robot with a keypad controlled by another robot with a keypad.
+input -> keypad -> keypad -> keypad -> numberpad
+
+we need to return the min number of keypresses needed for the input
+part 2:
+
+we now have 26 keypads before the number pad and we need to find the min amound of keypresses needed
+
+*/
+
+use aoc_common::{parse, search};
+use aoc_common::{Dir4, ParseError, ParseResult, Point2, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Numpad {
+    numpad: HashMap<Point2, char>,
+}
+
+impl Numpad {
+    fn new() -> Self {
+        let mut numpad: HashMap<Point2, char> = HashMap::new();
+
+        numpad.insert(Point2::new(0, 0), '7');
+        numpad.insert(Point2::new(1, 0), '8');
+        numpad.insert(Point2::new(2, 0), '9');
+        numpad.insert(Point2::new(0, 1), '4');
+        numpad.insert(Point2::new(1, 1), '5');
+        numpad.insert(Point2::new(2, 1), '6');
+        numpad.insert(Point2::new(0, 2), '1');
+        numpad.insert(Point2::new(1, 2), '2');
+        numpad.insert(Point2::new(2, 2), '3');
+        numpad.insert(Point2::new(1, 3), '0');
+        numpad.insert(Point2::new(2, 3), 'A');
+
+        Numpad { numpad }
+    }
+}
+
+#[derive(Debug)]
+struct Keypad {
+    keypad: HashMap<Point2, char>,
+}
+
+impl Keypad {
+    fn new() -> Self {
+        let mut keypad: HashMap<Point2, char> = HashMap::new();
+
+        keypad.insert(Point2::new(1, 0), '^');
+        keypad.insert(Point2::new(2, 0), 'A');
+        keypad.insert(Point2::new(0, 1), '<');
+        keypad.insert(Point2::new(1, 1), 'v');
+        keypad.insert(Point2::new(2, 1), '>');
+
+        Keypad { keypad }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+struct Arm {
+    current_coord: Point2,
+    facing_dir: Dir4,
+}
+
+fn common_djikstra(
+    djikstra_cache: &mut HashMap<(char, char), Vec<Vec<char>>>,
+    character_pad: &HashMap<Point2, char>,
+    start_char: &char,
+    end_char: &char,
+) -> Vec<Vec<char>> {
+    // return vec of chars is the instructions to get to the end, for example >>^^
+
+    if let Some(cached_result) = djikstra_cache.get(&(*start_char, *end_char)) {
+        return cached_result.clone();
+    }
+
+    let start_coord = character_pad
+        .iter()
+        .find(|(_, char)| *char == start_char)
+        .map(|(coord, _)| *coord)
+        .unwrap();
+
+    // the arm can start facing anywhere, keeping the direction costs 1 and changing it costs 2
+    let starts = Dir4::ALL.map(|facing_dir| Arm {
+        current_coord: start_coord,
+        facing_dir,
+    });
+
+    let lowest_paths = search::all_shortest_paths(
+        starts,
+        |&arm| {
+            Dir4::ALL.into_iter().filter_map(move |new_dir| {
+                let current_coord = arm.current_coord + new_dir;
+                character_pad.contains_key(&current_coord).then(|| {
+                    let cost = if new_dir == arm.facing_dir { 1 } else { 2 };
+                    let next = Arm {
+                        current_coord,
+                        facing_dir: new_dir,
+                    };
+                    (next, cost)
+                })
+            })
+        },
+        |arm| character_pad.get(&arm.current_coord) == Some(end_char),
+    )
+    .unwrap();
+
+    let possible_paths: Vec<Vec<char>> = lowest_paths
+        .paths()
+        .into_iter()
+        .map(|path| path[1..].iter().map(|arm| arm.facing_dir.arrow()).collect())
+        .collect();
+
+    djikstra_cache.insert((*start_char, *end_char), possible_paths.clone());
+
+    possible_paths
+}
+
+fn recursive_keypad_pusher(
+    use_numpad: bool,
+    numpad: &HashMap<Point2, char>,
+    keypad: &HashMap<Point2, char>,
+    input: Vec<char>,
+    robots: u16,
+    djikstra_cache: &mut HashMap<(char, char), Vec<Vec<char>>>,
+    input_cache: &mut HashMap<(Vec<char>, u16), u64>,
+) -> u64 {
+    if robots == 0 {
+        return input.len() as u64 - 1;
+    }
+
+    if let Some(&cached_result) = input_cache.get(&(input.clone(), robots)) {
+        return cached_result;
+    }
+
+    let mut total_presses = 0;
+
+    for key_pair in input.windows(2) {
+        let new_directions = if use_numpad {
+            common_djikstra(djikstra_cache, numpad, &key_pair[0], &key_pair[1])
+        } else {
+            common_djikstra(djikstra_cache, keypad, &key_pair[0], &key_pair[1])
+        };
+
+        let mut min_presses = u64::MAX;
+
+        for new_dirs in new_directions {
+            let mut press_sequence = vec!['A'];
+            press_sequence.extend(new_dirs);
+            press_sequence.push('A');
+
+            let total_key_persses = recursive_keypad_pusher(
+                false,
+                numpad,
+                keypad,
+                press_sequence,
+                robots - 1,
+                djikstra_cache,
+                input_cache,
+            );
+
+            min_presses = min_presses.min(total_key_persses);
+        }
+
+        total_presses += min_presses;
+    }
+
+    input_cache.insert((input, robots), total_presses);
+    total_presses
+}
+
+pub fn min_keypresses(input: &[String], robot_count: u16) -> u64 {
+    let mut total = 0;
+
+    let mut djikstra_cache: HashMap<(char, char), Vec<Vec<char>>> = HashMap::new();
+    let mut input_cache: HashMap<(Vec<char>, u16), u64> = HashMap::new();
+
+    for keypad in input {
+        let keypad_num: u64 = keypad.chars().take(3).collect::<String>().parse().unwrap();
+
+        let mut input_chars: Vec<char> = keypad.chars().collect();
+        input_chars.insert(0, 'A');
+
+        let total_presses = recursive_keypad_pusher(
+            true,
+            &Numpad::new().numpad,
+            &Keypad::new().keypad,
+            input_chars,
+            robot_count,
+            &mut djikstra_cache,
+            &mut input_cache,
+        );
+
+        total += total_presses * keypad_num;
+    }
+
+    total
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        // "029A": the three digits of the code and then the A that enters it
+        let expected = |column| match column {
+            0..=2 => "a digit",
+            3 => "'A'",
+            _ => "end of line",
+        };
+        for (line, code) in input.iter().enumerate() {
+            for (column, c) in code.char_indices() {
+                let valid = match column {
+                    0..=2 => c.is_ascii_digit(),
+                    3 => c == 'A',
+                    _ => false,
+                };
+                if !valid {
+                    return Err(parse::unexpected_char(line, column, c, expected(column)));
+                }
+            }
+            if code.len() < 4 {
+                let end = &code[code.len()..];
+                return Err(ParseError::at(line, code, end, expected(code.len())));
+            }
+        }
+
+        Ok(input.to_vec())
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        min_keypresses(input, 3)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        min_keypresses(input, 26)
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_2024_21::{min_keypresses, Day};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check("example_1.txt", min_keypresses(&example, 3), 126384);
+}
@@ -0,0 +1,158 @@
+/*
+part 1:
+we sell hiding spots in order to buy bananas with them.
+we have to pick the perfect time to see in order to get the most bananas.
+we have a secret number that we use to derive other secret nubmers from using a formula.
+we can then use these secret numbers to predict prices
+
+we get the 2000th number for each initial number and sum them  up
+
+part 2:
+
+the price is the number at the first position in the secret number.
+monkey will sell only after a specific sequence of changes.
+
+we have to find the best 4 len difference sequence in the price to tell the monkey that gives us the most bananas
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution};
+use fxhash::{FxHashMap, FxHashSet};
+
+#[derive(Clone)]
+pub struct SecretNum {
+    num: i64,
+    seq_value: FxHashMap<[i8; 4], u8>,
+    price: Vec<u8>,
+    diff: Vec<i8>,
+}
+
+impl SecretNum {
+    fn from_string(line: usize, input: &str) -> ParseResult<Self> {
+        Ok(SecretNum {
+            num: parse::number(line, input, input)?,
+            seq_value: FxHashMap::default(),
+            price: vec![],
+            diff: vec![],
+        })
+    }
+
+    fn recursive_secret_num_finder(&mut self, mut current: i64, counter: u32) -> i64 {
+        let price = (current % 10) as u8;
+
+        if let Some(&last_price) = self.price.last() {
+            let diff = price as i8 - last_price as i8;
+            self.diff.push(diff);
+        } else {
+            self.diff.push(0);
+        }
+
+        self.price.push(price);
+        if counter == 0 {
+            return current;
+        }
+
+        let mix_num = current * 64;
+        Self::mix(&mut current, mix_num);
+        Self::prune(&mut current);
+
+        let mix_num_2 = (current as f64 / 32.0).floor() as i64;
+        Self::mix(&mut current, mix_num_2);
+        Self::prune(&mut current);
+
+        let mix_num_3 = current * 2048;
+        Self::mix(&mut current, mix_num_3);
+        Self::prune(&mut current);
+
+        self.recursive_secret_num_finder(current, counter - 1)
+    }
+
+    fn mix(current: &mut i64, value: i64) {
+        *current ^= value;
+    }
+
+    fn prune(current: &mut i64) {
+        *current %= 16777216;
+    }
+
+    fn populate_seq_values(&mut self) {
+        for (i, diff_seq) in self.diff.windows(4).enumerate() {
+            if !self.seq_value.contains_key(diff_seq) {
+                let index = i + 3;
+                let current_price = self.price[index];
+                let key: [i8; 4] = diff_seq.try_into().unwrap();
+                self.seq_value.insert(key, current_price);
+            }
+        }
+    }
+}
+
+fn secret_number_finder(secret_nums: &[SecretNum]) -> i64 {
+    let mut secret_nums = secret_nums.to_owned();
+    let mut total = 0;
+
+    for secret in secret_nums.iter_mut() {
+        let result = secret.recursive_secret_num_finder(secret.num, 2000);
+
+        total += result;
+    }
+
+    total
+}
+
+fn find_best_buying_sequence(secret_nums: &[SecretNum]) -> u32 {
+    let mut secret_nums = secret_nums.to_owned();
+    for secret in secret_nums.iter_mut() {
+        secret.recursive_secret_num_finder(secret.num, 2000);
+        secret.populate_seq_values();
+    }
+
+    let all_existing_sequences: FxHashSet<&[i8; 4]> = secret_nums
+        .iter()
+        .flat_map(|secret| secret.seq_value.keys())
+        .collect();
+
+    let mut best_banana_count: u32 = 0;
+
+    for possible_seq in all_existing_sequences {
+        let mut seq_bananas: u32 = 0;
+        for secret in &secret_nums {
+            if let Some(&banana_count) = secret.seq_value.get(possible_seq) {
+                seq_bananas += banana_count as u32
+            }
+        }
+
+        best_banana_count = best_banana_count.max(seq_bananas)
+    }
+
+    best_banana_count
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<SecretNum>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, s)| SecretNum::from_string(line, s))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SecretNum>;
+    type Answer1 = i64;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i64 {
+        secret_number_finder(input)
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        find_best_buying_sequence(input)
+    }
+}
@@ -0,0 +1,91 @@
+/*
+part 1:
+we are at a lan party with a lot of connected computers
+we know all the connections from computer to computer
+we have to find the sets where 3 computers are all connected to eachother and at least one computers
+name starts with t and then return the count of those sets
+part 2:
+
+we have to find the largest group where all the computers are connected to eachother
+and return those IDs as a string in alphabetical order
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{Graph, ParseResult, Solution};
+
+#[derive(Debug)]
+pub struct Connections {
+    connected: Graph<String>,
+}
+
+impl Connections {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let mut all_connections = Graph::new();
+
+        for (line, connect) in input.iter().enumerate() {
+            let (c1, c2) = parse::split_once(line, connect, connect, "-")?;
+            all_connections.add_undirected_edge(c1.to_owned(), c2.to_owned());
+        }
+
+        Ok(Connections {
+            connected: all_connections,
+        })
+    }
+}
+
+fn find_connected_nodes_with_t_1(connections: &Connections) -> u32 {
+    let graph = &connections.connected;
+    let mut t_connections = 0;
+
+    // every triangle is counted once, from its lowest id through the ids going up
+    for pc_1 in graph.nodes() {
+        for &pc_2 in graph.neighbours(pc_1).iter().filter(|&&pc_2| pc_2 > pc_1) {
+            for &pc_3 in graph.neighbours(pc_2).iter().filter(|&&pc_3| pc_3 > pc_2) {
+                if graph.has_edge(pc_1, pc_3)
+                    && [pc_1, pc_2, pc_3]
+                        .iter()
+                        .any(|&pc| graph.name(pc).starts_with('t'))
+                {
+                    t_connections += 1;
+                }
+            }
+        }
+    }
+
+    t_connections
+}
+
+fn largest_connected_group_2(connections: &Connections) -> String {
+    let graph = &connections.connected;
+
+    let mut largest_connection: Vec<&str> = graph
+        .max_clique()
+        .into_iter()
+        .map(|pc| graph.name(pc).as_str())
+        .collect();
+
+    largest_connection.sort();
+
+    largest_connection.join(",")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Connections;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Connections::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        find_connected_nodes_with_t_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> String {
+        largest_connected_group_2(input)
+    }
+}
@@ -0,0 +1,237 @@
+/*
+part 1:
+we have devices that produce numbers with boolean logic
+each gate will wait for both of it's input numbers to be filled and will produce a single output in the circuit
+
+part 2:
+
+instead of adding individual numbers together, we are trying to add numbers togther
+bits that start with x are all one number, bits starting with y are all one number
+and the output is put on wires that start with z
+00 is the least significan bit, then 01 and then 02...
+
+but one problem is that we have 4 pairs of gates where the output wire has been swapped
+and we have to find the gates that have been swapped and give them as output alphabetically joined by commas
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+use fxhash::{FxHashMap, FxHashSet};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Gate {
+    AND,
+    OR,
+    XOR,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Instruction {
+    num1: String,
+    num2: String,
+    target: String,
+    gate: Gate,
+}
+
+#[derive(Debug, Clone)]
+pub struct AllInstructions {
+    values: FxHashMap<String, bool>,
+    instructions: Vec<Instruction>,
+}
+
+impl AllInstructions {
+    fn from_string(input: &[String]) -> ParseResult<Self> {
+        let mut num_strings: Vec<(usize, &str)> = vec![];
+        let mut instruction_strings: Vec<(usize, &str)> = vec![];
+        let mut nums_section = true;
+
+        for (i, line) in input.iter().enumerate() {
+            if line.is_empty() {
+                nums_section = false;
+                continue;
+            }
+
+            if nums_section {
+                num_strings.push((i, line));
+            } else {
+                instruction_strings.push((i, line));
+            }
+        }
+
+        let mut start_values: FxHashMap<String, bool> = FxHashMap::default();
+        let mut all_instructions: Vec<Instruction> = vec![];
+
+        for (i, line) in num_strings {
+            let (id, val) = parse::split_once(i, line, line, ": ")?;
+            let bool_val = match val {
+                "0" => false,
+                "1" => true,
+                _ => return Err(ParseError::at(i, line, val, "0 or 1")),
+            };
+
+            start_values.insert(id.to_string(), bool_val);
+        }
+
+        let mut instruction_lines: Vec<(usize, &str)> = vec![];
+
+        for (i, line) in instruction_strings {
+            let sections: Vec<&str> = line.split(" ").collect();
+            if sections.len() != 5 || sections[3] != "->" {
+                return Err(ParseError::line(i, line, "\"x00 AND y00 -> z00\""));
+            }
+
+            let inst = match sections[1] {
+                "AND" => Gate::AND,
+                "OR" => Gate::OR,
+                "XOR" => Gate::XOR,
+                _ => return Err(ParseError::at(i, line, sections[1], "AND, OR or XOR")),
+            };
+
+            let new_ins = Instruction {
+                num1: sections[0].to_owned(),
+                num2: sections[2].to_owned(),
+                target: sections[4].to_owned(),
+                gate: inst,
+            };
+
+            all_instructions.push(new_ins);
+            instruction_lines.push((i, line));
+        }
+
+        check_gates(&start_values, &all_instructions, &instruction_lines)?;
+
+        Ok(AllInstructions {
+            values: start_values,
+            instructions: all_instructions,
+        })
+    }
+
+    fn process_instructions(&mut self) {
+        let mut available_instructions: Vec<Instruction> = vec![];
+        loop {
+            self.instructions.retain(|ins| {
+                let all_values: Vec<&String> = self.values.keys().collect();
+
+                if all_values.contains(&&ins.num1) && all_values.contains(&&ins.num2) {
+                    available_instructions.push(ins.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+
+            for ins in &available_instructions {
+                let val_1 = self.values[&ins.num1];
+                let val_2 = self.values[&ins.num2];
+
+                let result = match ins.gate {
+                    Gate::AND => val_1 & val_2,
+                    Gate::OR => val_1 | val_2,
+                    Gate::XOR => val_1 ^ val_2,
+                };
+
+                self.values.insert(ins.target.clone(), result);
+            }
+
+            if self.instructions.is_empty() {
+                break;
+            }
+        }
+    }
+
+    fn values_to_num(&self, start_char: char) -> u64 {
+        let bool_values = self.values_to_bool(start_char);
+
+        bool_values
+            .iter()
+            .fold(0, |acc, bit| (acc << 1) | *bit as u64)
+    }
+
+    fn values_to_bool(&self, start_char: char) -> Vec<bool> {
+        let mut copy_values = self.values.clone();
+
+        copy_values.retain(|s, _| s.starts_with(start_char));
+
+        let mut key_value_pairs: Vec<(&String, &bool)> = copy_values.iter().collect();
+
+        key_value_pairs.sort_by(|a, b| a.0.cmp(b.0));
+
+        key_value_pairs
+            .into_iter()
+            .rev()
+            .map(|(_, val)| *val)
+            .collect()
+    }
+}
+
+// every gate has to get both of its inputs eventually, otherwise the circuit never settles
+fn check_gates(
+    start_values: &FxHashMap<String, bool>,
+    instructions: &[Instruction],
+    lines: &[(usize, &str)],
+) -> ParseResult<()> {
+    let mut set_wires: FxHashSet<&String> = start_values.keys().collect();
+    let mut waiting: Vec<usize> = (0..instructions.len()).collect();
+
+    loop {
+        let waiting_count = waiting.len();
+        waiting.retain(|&i| {
+            let ins = &instructions[i];
+            if set_wires.contains(&ins.num1) && set_wires.contains(&ins.num2) {
+                set_wires.insert(&ins.target);
+                false
+            } else {
+                true
+            }
+        });
+
+        match waiting.first() {
+            None => return Ok(()),
+            Some(&i) if waiting.len() == waiting_count => {
+                let (line, text) = lines[i];
+                return Err(ParseError::line(
+                    line,
+                    text,
+                    "a gate whose inputs are set at the start or by another gate",
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn run_circuit_1(all_instructions: &AllInstructions) -> u64 {
+    let mut all_instructions = all_instructions.clone();
+
+    all_instructions.process_instructions();
+
+    all_instructions.values_to_num('z')
+}
+
+fn swapped_wires_2(_all_instructions: &AllInstructions) -> String {
+    "".to_owned()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = AllInstructions;
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    const PLACEHOLDERS: &'static [u8] = &[2];
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        AllInstructions::from_string(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        run_circuit_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> String {
+        swapped_wires_2(input)
+    }
+}
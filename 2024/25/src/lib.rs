@@ -0,0 +1,142 @@
+/*
+part 1:
+
+we have to pick a lock. We have a bunch of keys to try on the lock and we have to rule out some ouf them.
+
+we have locks and keys that are 2d grids, with empty space being (.) and filled space being (#) and based on the filled space
+we have to check if the key can possible fit the lock without overlapping with the filled in space.
+
+we have to check all the key lock combinations that work and then return the count of fitting keys
+
+part 2:
+
+finish every other day in the AoC year
+
+*/
+
+use aoc_common::{input, parse};
+use aoc_common::{ParseError, ParseResult, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lock {
+    pins: [u8; 5],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    pins: [u8; 5],
+}
+
+fn fitting_lock_key_combinations(locks: &[Lock], keys: &[Key]) -> u32 {
+    let mut fit_pairs = 0;
+
+    for curr_lock in locks {
+        for curr_key in keys {
+            if curr_lock
+                .pins
+                .iter()
+                .zip(curr_key.pins.iter())
+                .all(|(lock_pin, key_pin)| key_pin <= lock_pin)
+            {
+                fit_pairs += 1;
+            }
+        }
+    }
+
+    fit_pairs
+}
+
+fn final_star_2(_locks: &[Lock], _keys: &[Key]) -> u32 {
+    0
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(Vec<Lock>, Vec<Key>)> {
+    parse::grid_chars(input, "#.")?;
+
+    let mut key_lock_strings: Vec<Vec<String>> = vec![];
+
+    // every key and lock is 5 pins wide and 7 rows tall
+    for block in input::blocks(input) {
+        if block.lines.len() != 7 {
+            let last = block.start + block.lines.len() - 1;
+            return Err(ParseError::line(
+                last,
+                &input[last],
+                "a key or lock 7 rows tall",
+            ));
+        }
+
+        for (i, text) in block.lines.iter().enumerate() {
+            if text.len() != 5 {
+                return Err(ParseError::line(block.start + i, text, "a row of 5 pins"));
+            }
+        }
+
+        key_lock_strings.push(block.lines.to_vec());
+    }
+
+    let mut all_locks = vec![];
+    let mut all_keys = vec![];
+
+    for key_lock in key_lock_strings {
+        let is_lock = key_lock[0].chars().all(|c| c == '#');
+        let rotated = rotate(key_lock);
+
+        let mut lock_nums: Vec<u8> = vec![];
+        if is_lock {
+            for line in &rotated {
+                lock_nums.push(line.chars().filter(|c| *c == '.').count() as u8);
+            }
+            let pins: [u8; 5] = lock_nums.try_into().unwrap();
+            all_locks.push(Lock { pins });
+        } else {
+            for line in &rotated {
+                lock_nums.push(line.chars().filter(|c| *c == '#').count() as u8);
+            }
+            let pins: [u8; 5] = lock_nums.try_into().unwrap();
+
+            all_keys.push(Key { pins });
+        }
+    }
+
+    Ok((all_locks, all_keys))
+}
+
+fn rotate(grid: Vec<String>) -> Vec<String> {
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    let mut rotated = vec![String::new(); cols];
+
+    for (col, rotated_row) in rotated.iter_mut().enumerate() {
+        let mut new_row = String::new();
+        for row in (0..rows).rev() {
+            new_row.push(grid[row].chars().nth(col).unwrap());
+        }
+        *rotated_row = new_row;
+    }
+
+    rotated
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Lock>, Vec<Key>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PLACEHOLDERS: &'static [u8] = &[2];
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((locks, keys): &Self::Input) -> u32 {
+        fitting_lock_key_combinations(locks, keys)
+    }
+
+    fn part_2((locks, keys): &Self::Input) -> u32 {
+        final_star_2(locks, keys)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_2024_25::Day;
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 3);
+}
@@ -1,59 +1,6 @@
-/*
-part 1:
-
-part 2:
-
-*/
-
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_2024_template::Day;
+use aoc_common::harness;
 
 fn main() {
-    let my_input = read_file("my_input.txt");
-
-    part_1(&my_input);
-    part_2(&my_input);
-}
-
-fn part_1(_my_input: &[String]) {
-    let example_1 = read_file("example_1.txt");
-    dbg!(&example_1);
-
-    let example_sum = solution_1(&example_1);
-    dbg!(&example_sum);
-    assert_eq!(example_sum, 0);
-
-    let my_sum = solution_1(_my_input);
-    dbg!(my_sum);
-}
-
-fn part_2(_my_input: &[String]) {
-    let example_2 = read_file("example_2.txt");
-    dbg!(&example_2);
-
-    let example_sum = solution_2(&example_2);
-    dbg!(&example_sum);
-    assert_eq!(example_sum, 0);
-
-    let my_sum = solution_2(_my_input);
-    dbg!(my_sum);
-}
-
-fn solution_1(input: &[String]) -> u32 {
-    let parsed = parse_input(input);
-    0
-}
-
-fn solution_2(input: &[String]) -> u32 {
-    let parsed = parse_input(input);
-    0
-}
-
-fn parse_input(input: &[String]) {}
-
-fn read_file(file_name: &str) -> Vec<String> {
-    let file = File::open(file_name).expect("File not found");
-    let reader = BufReader::new(file);
-
-    reader.lines().map_while(Result::ok).collect()
+    harness::run::<Day>("my_input.txt");
 }
@@ -0,0 +1,104 @@
+/*
+part 1:
+
+we turn a dial left and right and see how many times it hits 0 after we have turned the dial
+
+part 2:
+
+we also have to count how many times it hits 0 during the dial turning
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+struct Dial(i16);
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial(50)
+    }
+}
+
+impl Dial {
+    fn turn_1(&mut self, amount: i16) -> u8 {
+        let mut zeros: u8 = 0;
+        let dir = amount.signum();
+
+        for _ in 0..amount.abs() {
+            self.0 += dir;
+            if self.0 == -1 {
+                self.0 = 99
+            } else if self.0 == 100 {
+                self.0 = 0
+            }
+        }
+
+        zeros += (self.0 == 0) as u8;
+
+        zeros
+    }
+
+    fn turn_2(&mut self, amount: i16) -> u8 {
+        let mut zeros: u8 = 0;
+        let dir = amount.signum();
+
+        for _ in 0..amount.abs() {
+            self.0 += dir;
+            if self.0 == -1 {
+                self.0 = 99
+            } else if self.0 == 100 {
+                self.0 = 0
+            }
+            zeros += (self.0 == 0) as u8;
+        }
+
+        zeros
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i16>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed: &Self::Input) -> u16 {
+        let mut count = 0;
+        let mut dial = Dial::default();
+        for num in parsed {
+            count += dial.turn_1(*num) as u16;
+        }
+        count
+    }
+
+    fn part_2(parsed: &Self::Input) -> u16 {
+        let mut count = 0;
+        let mut dial = Dial::default();
+        for num in parsed {
+            count += dial.turn_2(*num) as u16;
+        }
+        count
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<i16>> {
+    let mut turns = Vec::new();
+    for (i, line) in input.iter().enumerate() {
+        let count = if let Some(count_str) = line.strip_prefix("R") {
+            parse::number::<i16>(i, line, count_str)?
+        } else if let Some(count_str) = line.strip_prefix("L") {
+            -parse::number::<i16>(i, line, count_str)?
+        } else {
+            return Err(ParseError::line(i, line, "'R' or 'L' followed by a number"));
+        };
+        turns.push(count);
+    }
+
+    Ok(turns)
+}
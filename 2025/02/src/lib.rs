@@ -0,0 +1,107 @@
+/*
+part 1:
+we have to check a range to see if there are any numbers that are made up of 2 repeating number sequences, for example 1212 is invalid
+
+part 2:
+
+number that consists of repeating sub numbers is also invalid, so 123123123123 is invalid
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution};
+use std::ops::RangeInclusive;
+
+pub struct IDRange(RangeInclusive<u64>);
+
+impl IDRange {
+    fn new(lower: u64, upper: u64) -> Self {
+        IDRange(RangeInclusive::new(lower, upper))
+    }
+    fn get_invalid_count_1(&self) -> u64 {
+        let mut count = 0;
+        for num in self.0.clone() {
+            let num_str = num.to_string();
+            if num_str.len() % 2 != 0 {
+                continue;
+            }
+
+            let half_len = num_str.len() / 2;
+
+            if num_str[..half_len] == num_str[half_len..] {
+                count += num;
+            }
+        }
+        count
+    }
+
+    fn get_invalid_count_2(&self) -> u64 {
+        let mut count = 0;
+        for num in self.0.clone() {
+            let num_str = num.to_string();
+            let num_bytes = num_str.as_bytes();
+            let half_len = num_bytes.len() / 2;
+
+            for sub_range in 1..=half_len {
+                if num_bytes.len() % sub_range != 0 {
+                    continue;
+                }
+
+                let repeating: &[u8] = &num_bytes[..sub_range];
+
+                let matches = num_bytes
+                    .chunks(sub_range)
+                    .skip(1)
+                    .all(|window| window == repeating);
+
+                if matches {
+                    count += num;
+                    break;
+                }
+            }
+        }
+        count
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<IDRange>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed: &Self::Input) -> u64 {
+        let mut count = 0;
+        for range in parsed {
+            count += range.get_invalid_count_1();
+        }
+        count
+    }
+
+    fn part_2(parsed: &Self::Input) -> u64 {
+        let mut count = 0;
+        for range in parsed {
+            count += range.get_invalid_count_2();
+        }
+        count
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<IDRange>> {
+    let ranges_line = parse::line(input, 0)?;
+    let mut id_ranges = Vec::new();
+    for range in ranges_line.split(",") {
+        let (lower_str, upper_str) = parse::split_once(0, ranges_line, range, "-")?;
+        let lower: u64 = parse::number(0, ranges_line, lower_str)?;
+        let upper: u64 = parse::number(0, ranges_line, upper_str)?;
+
+        id_ranges.push(IDRange::new(lower, upper));
+    }
+
+    Ok(id_ranges)
+}
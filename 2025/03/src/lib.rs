@@ -0,0 +1,79 @@
+/*
+part 1:
+we have banks of batteries, we only activate 2 of them to get the highest joltage per bank and add them together
+part 2:
+we now instead also get the highest joltage of 12 batteries in the bank
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct BatteryBank(Vec<u8>);
+impl BatteryBank {
+    #[allow(clippy::ptr_arg)]
+    fn new(line: usize, input: &str) -> ParseResult<Self> {
+        let mut batteries = Vec::new();
+        for (column, c) in input.char_indices() {
+            batteries.push(parse::digit(line, column, c)? as u8);
+        }
+
+        // part 2 turns on 12 batteries in every bank
+        if batteries.len() < 12 {
+            return Err(ParseError::line(
+                line,
+                input,
+                "a bank of at least 12 batteries",
+            ));
+        }
+
+        Ok(BatteryBank(batteries))
+    }
+
+    fn get_highest_joltage(&self, mut num_count: u16) -> u64 {
+        let mut total: u64 = 0;
+        let mut start_idx = 0;
+        let total_len = self.0.len();
+        while num_count > 0 {
+            let num_search_slice = &self.0[start_idx..(total_len - (num_count - 1) as usize)];
+            let (idx, max_val) = num_search_slice
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|(_, num)| *num)
+                .unwrap();
+
+            start_idx += idx + 1;
+            total += (*max_val as u64) * 10u64.pow(num_count as u32 - 1);
+
+            num_count -= 1;
+        }
+        total
+    }
+}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<BatteryBank>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(banks: &Self::Input) -> u64 {
+        banks.iter().map(|b| b.get_highest_joltage(2)).sum()
+    }
+
+    fn part_2(banks: &Self::Input) -> u64 {
+        banks.iter().map(|b| b.get_highest_joltage(12)).sum()
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<BatteryBank>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, bank)| BatteryBank::new(line, bank))
+        .collect()
+}
@@ -0,0 +1,89 @@
+/*
+part 1:
+we have a grid of papers and we need to check if they are reachable, they are only reachable if there is less than 4 other
+papers in the 8 adjacent tiles, then we jus return the count of reachable papers
+part 2:
+we now remove the rolls that we can reach and we need to recheck if we can reach more papers
+
+*/
+
+use aoc_common::{Grid, ParseResult, Solution};
+
+#[derive(Clone)]
+pub struct PaperGrid(Grid<bool>);
+
+impl PaperGrid {
+    fn new(input: &[String]) -> ParseResult<Self> {
+        Ok(PaperGrid(Grid::parse(input, ".@", |c| c == '@')?))
+    }
+
+    fn papers(&self) -> Vec<(usize, usize)> {
+        self.0.find_all(&true).collect()
+    }
+
+    fn find_reachable(&self, search_space: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut reachable: Vec<(usize, usize)> = search_space
+            .iter()
+            .copied()
+            // the paper is reachable with less than 4 papers around it
+            .filter(|&pos| self.0.neighbours8(pos).filter(|&next| self.0[next]).count() < 4)
+            .collect();
+
+        reachable.sort();
+        reachable.dedup();
+
+        reachable
+    }
+
+    fn find_and_remove(mut self) -> u32 {
+        let mut reachable = 0;
+
+        let mut seach_papers = self.papers();
+
+        loop {
+            let reached_papers = self.find_reachable(&seach_papers);
+
+            if reached_papers.is_empty() {
+                break;
+            }
+
+            reachable += reached_papers.len();
+
+            for &pos in &reached_papers {
+                self.0[pos] = false;
+            }
+
+            seach_papers.clear();
+
+            for pos in reached_papers {
+                seach_papers.extend(self.0.neighbours8(pos).filter(|&next| self.0[next]));
+            }
+        }
+
+        reachable as u32
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = PaperGrid;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(paper_grid: &Self::Input) -> u32 {
+        paper_grid.find_reachable(&paper_grid.papers()).len() as u32
+    }
+
+    fn part_2(paper_grid: &Self::Input) -> u32 {
+        paper_grid.clone().find_and_remove()
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<PaperGrid> {
+    PaperGrid::new(input)
+}
@@ -0,0 +1,81 @@
+/*
+part 1:
+we have to search for fresh and spoiled ingredients with ids from fresh id ranges. we need to return the count of fresh ingredients
+part 2:
+we have to find the total number of fresh ids, the problem is that the ranges can be overlapping
+
+*/
+
+use aoc_common::input::{self, Block};
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, RangeSet, Solution};
+
+#[derive(Clone)]
+pub struct FreshIDs(RangeSet<u64>);
+impl FreshIDs {
+    fn new(block: &Block) -> ParseResult<Self> {
+        let mut ranges = RangeSet::new();
+        for (i, str) in block.lines.iter().enumerate() {
+            let line = block.start + i;
+            let (start_str, end_str) = parse::split_once(line, str, str, "-")?;
+            let start: u64 = parse::number(line, str, start_str)?;
+            let end: u64 = parse::number(line, str, end_str)?;
+            ranges.insert(start..end + 1);
+        }
+
+        Ok(FreshIDs(ranges))
+    }
+
+    fn find_fresh_produce(&self, ids: &[u64]) -> Vec<u64> {
+        ids.iter()
+            .filter(|id| self.0.contains(id))
+            .copied()
+            .collect()
+    }
+
+    // the overlapping ranges are merged on insert, so the ids are never counted twice
+    fn find_num_fresh_ids(&self) -> u64 {
+        self.0.len()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (FreshIDs, Vec<u64>);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((fresh_ids, ids): &Self::Input) -> u32 {
+        fresh_ids.find_fresh_produce(ids).len() as u32
+    }
+
+    fn part_2((fresh_ids, _): &Self::Input) -> u64 {
+        fresh_ids.find_num_fresh_ids()
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(FreshIDs, Vec<u64>)> {
+    let blocks = input::blocks(input);
+    let (Some(fresh_ids), Some(id_strings)) = (blocks.first(), blocks.get(1)) else {
+        return Err(ParseError::new(
+            input.len(),
+            0,
+            "an empty line before the ids",
+            "end of input",
+        ));
+    };
+
+    let ids: Vec<u64> = id_strings
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, s)| parse::number(id_strings.start + i, s, s))
+        .collect::<ParseResult<_>>()?;
+    let fresh_ranges = FreshIDs::new(fresh_ids)?;
+    Ok((fresh_ranges, ids))
+}
@@ -0,0 +1,152 @@
+/*
+part 1:
+we are solving cephalapods math homework with a list of problems, we need to add or multiply a group of numbers together
+the biggest problem is parsing the problem correctly
+
+part 2:
+we need to parse the problems differently, 1 column at a time
+
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseError, ParseResult, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<u64>, Vec<u64>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok((parse_input_1(input)?, parse_input_2(input)?))
+    }
+
+    fn part_1((math_nums, _): &Self::Input) -> u64 {
+        math_nums.iter().sum()
+    }
+
+    fn part_2((_, math_nums): &Self::Input) -> u64 {
+        math_nums.iter().sum()
+    }
+}
+
+fn parse_input_1(input: &[String]) -> ParseResult<Vec<u64>> {
+    let mut rows = Vec::new();
+    for row in input {
+        let split_row: Vec<&str> = row.split(" ").filter(|r| !r.is_empty()).collect();
+        rows.push(split_row);
+    }
+
+    let (operators, nums_rows) = rows
+        .split_last()
+        .ok_or_else(|| ParseError::new(0, 0, "a row of operators", "end of input"))?;
+    let operator_line = input.len() - 1;
+
+    let row_len = operators.len();
+
+    // one number per operator on every row
+    if let Some(line) = nums_rows.iter().position(|row| row.len() != row_len) {
+        return Err(ParseError::line(
+            line,
+            &input[line],
+            format!("{row_len} numbers"),
+        ));
+    }
+
+    let mut parsed = Vec::new();
+
+    for i in 0..row_len {
+        let mut nums = Vec::new();
+        for (line, row) in nums_rows.iter().enumerate() {
+            nums.push(parse::number::<u64>(line, &input[line], row[i])?);
+        }
+
+        match operators[i] {
+            "*" => {
+                parsed.push(nums.iter().product());
+            }
+            "+" => {
+                parsed.push(nums.iter().sum());
+            }
+            other => {
+                let operator_string = &input[operator_line];
+                return Err(ParseError::at(
+                    operator_line,
+                    operator_string,
+                    other,
+                    "'*' or '+'",
+                ));
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+// the operators and digits were already checked by `parse_input_1`
+fn parse_input_2(input: &[String]) -> ParseResult<Vec<u64>> {
+    let (operator_string, nums_rows) = input.split_last().unwrap();
+    let operator_line = nums_rows.len();
+    let mut operator_iter = operator_string.chars().filter(|c| *c != ' ');
+    let mut next_operator = || {
+        operator_iter.next().ok_or_else(|| {
+            let end = &operator_string[operator_string.len()..];
+            ParseError::at(operator_line, operator_string, end, "another operator")
+        })
+    };
+
+    let row_len = nums_rows[0].len();
+
+    let mut problem_nums = Vec::new();
+    let mut nums: Vec<u64> = Vec::new();
+    let mut row_chars: Vec<char>;
+
+    // the numbers are read top to bottom, so every row has to line up
+    if let Some((line, row)) = nums_rows
+        .iter()
+        .enumerate()
+        .find(|(_, row)| row.len() != row_len)
+    {
+        return Err(ParseError::line(line, row, format!("{row_len} characters")));
+    }
+
+    let mut row_iterators: Vec<_> = nums_rows.iter().map(|row| row.chars()).collect();
+
+    for _ in 0..row_len {
+        row_chars = row_iterators
+            .iter_mut()
+            .filter_map(|iter| {
+                let next = iter.next().unwrap();
+                if next == ' ' { None } else { Some(next) }
+            })
+            .collect();
+
+        if row_chars.is_empty() {
+            match next_operator()? {
+                '*' => {
+                    problem_nums.push(nums.iter().product());
+                }
+                _ => {
+                    problem_nums.push(nums.iter().sum());
+                }
+            }
+
+            nums.clear();
+        } else {
+            let num_str: String = row_chars.iter().cloned().collect();
+            nums.push(num_str.parse().unwrap());
+        }
+    }
+
+    match next_operator()? {
+        '*' => {
+            problem_nums.push(nums.iter().product());
+        }
+        _ => {
+            problem_nums.push(nums.iter().sum());
+        }
+    }
+
+    Ok(problem_nums)
+}
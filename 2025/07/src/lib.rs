@@ -0,0 +1,146 @@
+/*
+part 1:
+we have a beam with a lot of splitters, we need to continue the beam and split it as many times as needed and count the amount of splits
+part 2:
+we actually have a quantum beam and we need to count all the possible ways the beam can split if it chooses either left or right at every splitter
+*/
+
+use ahash::AHashSet;
+use aoc_common::input::Input;
+use aoc_common::{Grid, Memo, ParseError, ParseResult, Solution};
+
+const SPLITTER: u8 = b'^';
+const SPACE: u8 = b'.';
+const START: u8 = b'S';
+
+#[derive(Clone)]
+pub struct TachyonBeam {
+    beam: AHashSet<usize>,
+    map: Vec<Vec<u8>>,
+}
+
+impl TachyonBeam {
+    fn new(input: &[&[u8]]) -> ParseResult<Self> {
+        let grid = Grid::parse_bytes(input, ".^S", |b| b)?;
+        if grid.height() < 2 {
+            return Err(ParseError::new(
+                1,
+                0,
+                "a row below the start",
+                "end of input",
+            ));
+        }
+
+        let (&start_row, rest) = input.split_first().unwrap();
+        if start_row.iter().filter(|&&b| b == START).count() != 1 {
+            return Err(ParseError::at_bytes(
+                0,
+                start_row,
+                start_row,
+                "a single start 'S' in the first row",
+            ));
+        }
+
+        for (y, &row) in rest.iter().enumerate() {
+            if let Some(x) = row.iter().position(|&b| b == START) {
+                return Err(ParseError::at_bytes(y + 1, row, &row[x..=x], "'.' or '^'"));
+            }
+
+            // a split beam goes both left and right, so it has to stay inside the manifold
+            for x in [0, row.len() - 1] {
+                if row[x] == SPLITTER {
+                    return Err(ParseError::at_bytes(
+                        y + 1,
+                        row,
+                        &row[x..=x],
+                        "a splitter away from the edges",
+                    ));
+                }
+            }
+        }
+
+        let beam: AHashSet<usize> = start_row
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b == START)
+            .map(|(i, _)| i)
+            .collect();
+        Ok(TachyonBeam {
+            beam,
+            map: rest.iter().map(|row| row.to_vec()).collect(),
+        })
+    }
+
+    fn split_beam(mut self) -> u32 {
+        let mut split_count = 0;
+
+        let max_len = self.map[0].len();
+        for row in &self.map {
+            for (i, &char) in row.iter().enumerate() {
+                if char == SPLITTER && self.beam.remove(&i) {
+                    split_count += 1;
+                    let prev = i - 1;
+                    let next = i + 1;
+                    if i > 0 {
+                        self.beam.insert(prev);
+                    }
+
+                    if next <= max_len {
+                        self.beam.insert(next);
+                    }
+                }
+            }
+        }
+
+        split_count
+    }
+
+    fn quantum_beam(memo: &mut Memo<(u8, u8), u64>, beam: usize, map: &[Vec<u8>]) -> u64 {
+        if map.is_empty() {
+            return 1;
+        }
+
+        let memo_key = (beam as u8, map.len() as u8);
+
+        memo.cached(memo_key, |memo| match map[0].get(beam) {
+            Some(&SPLITTER) => {
+                TachyonBeam::quantum_beam(memo, beam - 1, &map[1..])
+                    + TachyonBeam::quantum_beam(memo, beam + 1, &map[1..])
+            }
+            Some(&SPACE) => TachyonBeam::quantum_beam(memo, beam, &map[1..]),
+            None => 0,
+            _ => unreachable!(),
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = TachyonBeam;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        let rows: Vec<&[u8]> = input.iter().map(|row| row.as_bytes()).collect();
+        parse_input(&rows)
+    }
+
+    fn parse_input(input: &Input) -> ParseResult<Self::Input> {
+        parse_input(&input.byte_lines())
+    }
+
+    fn part_1(beam: &Self::Input) -> u32 {
+        beam.clone().split_beam()
+    }
+
+    fn part_2(beam: &Self::Input) -> u64 {
+        let mut memo = Memo::named("quantum beam");
+        let start = beam.beam.iter().next().unwrap();
+        TachyonBeam::quantum_beam(&mut memo, *start, &beam.map)
+    }
+}
+
+fn parse_input(input: &[&[u8]]) -> ParseResult<TachyonBeam> {
+    TachyonBeam::new(input)
+}
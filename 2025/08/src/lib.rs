@@ -0,0 +1,126 @@
+/*
+part 1:
+we have collections of junction boxes to connect in a way that reaches every single junction box
+the boxes are given in x,y,z coordinates
+we have to connect boxes that are as close to each other as possible
+after connecting together N closest pairs, we have a collection of circuits
+now we multiply the sizes of the 3 largerst circuits together to get the answer
+part 2:
+instead of connecting N largest, connect all into a large circuit
+we take the connection that is last needed for the single large circuit to be formed and we take those boxes X coordinates and multiply them together
+*/
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution, UnionFind};
+
+type BoxI = u16;
+
+pub struct BoxCoords {
+    x: Vec<u32>,
+    y: Vec<u32>,
+    z: Vec<u32>,
+}
+
+impl BoxCoords {
+    fn new(input: &[String]) -> ParseResult<Self> {
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+        let mut z = Vec::new();
+
+        for (line, string) in input.iter().enumerate() {
+            let (x_str, rest) = parse::split_once(line, string, string, ",")?;
+            let (y_str, z_str) = parse::split_once(line, string, rest, ",")?;
+            x.push(parse::number(line, string, x_str)?);
+            y.push(parse::number(line, string, y_str)?);
+            z.push(parse::number(line, string, z_str)?);
+        }
+
+        Ok(BoxCoords { x, y, z })
+    }
+}
+
+// every pair of boxes, closest first
+fn box_distances(boxes: &BoxCoords) -> Vec<(BoxI, BoxI)> {
+    let n_boxes = boxes.x.len();
+    let mut box_distance: Vec<(BoxI, BoxI, u64)> = Vec::with_capacity(n_boxes * n_boxes / 2);
+
+    for start_i in 0..n_boxes {
+        let start_x = boxes.x[start_i];
+        let start_y = boxes.y[start_i];
+        let start_z = boxes.z[start_i];
+
+        box_distance.extend(((start_i + 1)..n_boxes).map(|end_i| {
+            let x_dist = (start_x.abs_diff(boxes.x[end_i]) as u64).pow(2);
+            let y_dist = (start_y.abs_diff(boxes.y[end_i]) as u64).pow(2);
+            let z_dist = (start_z.abs_diff(boxes.z[end_i]) as u64).pow(2);
+            // the squared distance keeps the same order without the float rounding
+            (start_i as BoxI, end_i as BoxI, x_dist + y_dist + z_dist)
+        }));
+    }
+
+    box_distance.sort_unstable_by_key(|(_, _, dist)| *dist);
+
+    box_distance
+        .into_iter()
+        .map(|(box_i_1, box_i_2, _)| (box_i_1, box_i_2))
+        .collect()
+}
+
+pub fn largest_circuits_product(boxes: &BoxCoords, n_connections: usize) -> u64 {
+    let mut circuits = UnionFind::new(boxes.x.len());
+
+    for (box_i_1, box_i_2) in box_distances(boxes).into_iter().take(n_connections) {
+        circuits.union(box_i_1 as usize, box_i_2 as usize);
+    }
+
+    // get the product of 3 longest circuits
+    let mut circuit_lens = circuits.component_sizes();
+    circuit_lens.sort();
+
+    circuit_lens
+        .iter()
+        .rev()
+        .take(3)
+        .map(|num| *num as u64)
+        .product()
+}
+
+pub fn last_connection_x_product(boxes: &BoxCoords) -> u64 {
+    let mut circuits = UnionFind::new(boxes.x.len());
+
+    for (box_i_1, box_i_2) in box_distances(boxes) {
+        circuits.union(box_i_1 as usize, box_i_2 as usize);
+
+        if circuits.components() == 1 {
+            let box_1_x = boxes.x[box_i_1 as usize];
+            let box_2_x = boxes.x[box_i_2 as usize];
+            return box_1_x as u64 * box_2_x as u64;
+        }
+    }
+
+    0
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BoxCoords;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        largest_circuits_product(input, 1000)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        last_connection_x_product(input)
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<BoxCoords> {
+    BoxCoords::new(input)
+}
@@ -0,0 +1,13 @@
+use aoc_2025_08::{Day, largest_circuits_product};
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    let example = harness::parse_file::<Day>("example_1.txt");
+    harness::check("example_1.txt", largest_circuits_product(&example, 10), 40);
+}
+
+#[test]
+fn part_2() {
+    harness::example_2::<Day>("example_2.txt", 25272);
+}
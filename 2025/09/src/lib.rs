@@ -0,0 +1,100 @@
+/*
+part 1:
+we have a collection of possible tiles
+we want the biggest rectangle between the tiles possible
+part 2:
+
+all the tiles are connected by green tiles and we must have the biggest rectangle of red tile from corner to corner
+that does not go outside the marked area of red and green tiles
+
+*/
+
+use aoc_common::{ParseError, ParseResult, Point2, Solution};
+use aoc_common::{parse, polygon};
+
+// the red tiles are the corners of the area, in order
+pub struct Tiles(Vec<Point2>);
+
+impl Tiles {
+    fn new(input: &[String]) -> ParseResult<Self> {
+        let mut tiles_vec = Vec::new();
+        for (i, line) in input.iter().enumerate() {
+            let (x_str, y_str) = parse::split_once(i, line, line, ",")?;
+            tiles_vec.push(Point2::new(
+                parse::number(i, line, x_str)?,
+                parse::number(i, line, y_str)?,
+            ));
+        }
+
+        if tiles_vec.len() < 4 {
+            return Err(ParseError::new(
+                input.len(),
+                0,
+                "at least 4 red tiles",
+                "end of input",
+            ));
+        }
+
+        // each tile is in a straight line from the one before it, the last wraps to the first
+        for (i, line) in input.iter().enumerate() {
+            let previous = tiles_vec[(i + tiles_vec.len() - 1) % tiles_vec.len()];
+            let tile = tiles_vec[i];
+            if (tile.x() != previous.x()) == (tile.y() != previous.y()) {
+                return Err(ParseError::line(
+                    i,
+                    line,
+                    "a tile in the same row or column as the tile before it",
+                ));
+            }
+        }
+
+        Ok(Tiles(tiles_vec))
+    }
+
+    /// Every rectangle between two red tiles, the largest first
+    fn rectangles(&self) -> Vec<(u64, Point2, Point2)> {
+        let tiles = &self.0;
+        let mut rectangles = Vec::with_capacity(tiles.len() * tiles.len() / 2);
+
+        for (tile_1_i, &tile_1) in tiles.iter().enumerate() {
+            for &tile_2 in &tiles[tile_1_i + 1..] {
+                let size = tile_1 - tile_2;
+                let area = (size.x().unsigned_abs() + 1) * (size.y().unsigned_abs() + 1);
+                rectangles.push((area, tile_1, tile_2));
+            }
+        }
+
+        rectangles.sort_unstable_by_key(|&(area, _, _)| std::cmp::Reverse(area));
+        rectangles
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Tiles;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(tiles: &Self::Input) -> u64 {
+        tiles.rectangles()[0].0
+    }
+
+    fn part_2(tiles: &Self::Input) -> u64 {
+        // the red and green tiles make up the polygon with the red tiles as its corners
+        tiles
+            .rectangles()
+            .into_iter()
+            .find(|&(_, tile_1, tile_2)| polygon::rect_in_rectilinear(&tiles.0, tile_1, tile_2))
+            .map(|(area, _, _)| area)
+            .unwrap()
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Tiles> {
+    Tiles::new(input)
+}
@@ -0,0 +1,355 @@
+/*
+part 1:
+in the input we have light diagrams button wiring schematics and joltage requirements for each machine
+each combination turns on and turns off different light
+we have to find the combinations of lights to press in a way where we press the least amount of buttons to get to the
+wanted light combination
+
+part 2:
+now we have to take the joltage requirements into account
+now pressing the buttons adds 1 to the joltage of the machine whereever the buttons are wired
+we have to find the fewest button presses to get to the wanted joltages for each machine
+
+*/
+
+use ahash::AHashMap;
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_common::{math, parse};
+
+pub struct MachineRequirements {
+    lights_requirement: u16,
+    wiring_bits: Vec<u16>,
+    wiring_vec: Vec<Vec<u8>>,
+    joltage_requirements: Vec<u16>,
+}
+
+impl MachineRequirements {
+    // "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+    fn new(line: usize, input: &str) -> ParseResult<Self> {
+        let parts: Vec<&str> = input.split(" ").collect();
+
+        let [lights_str, buttons_str @ .., joltages_str] = parts.as_slice() else {
+            return Err(ParseError::line(
+                line,
+                input,
+                "lights, buttons and joltages",
+            ));
+        };
+        if buttons_str.is_empty() {
+            return Err(ParseError::line(
+                line,
+                input,
+                "lights, buttons and joltages",
+            ));
+        }
+
+        let lights_inner = MachineRequirements::bracketed(line, input, lights_str, '[', ']')?;
+        // the lights are stored as bits of a u16
+        if lights_inner.len() > 16 {
+            return Err(ParseError::at(line, input, lights_str, "at most 16 lights"));
+        }
+
+        let mut lights: u16 = 0;
+        for (i, c) in lights_inner.char_indices() {
+            match c {
+                '#' => lights |= 1 << i,
+                '.' => {}
+                _ => {
+                    let light = &lights_inner[i..i + c.len_utf8()];
+                    return Err(ParseError::at(line, input, light, "'#' or '.'"));
+                }
+            }
+        }
+        let light_count = lights_inner.len();
+
+        let mut button_vecs: Vec<Vec<u8>> = Vec::new();
+        for button_str in buttons_str {
+            let wiring = MachineRequirements::bracketed(line, input, button_str, '(', ')')?;
+
+            let mut button = Vec::new();
+            for light_str in wiring.split(",") {
+                let light: u8 = parse::number(line, input, light_str)?;
+                if light as usize >= light_count {
+                    let expected = format!("a light below {light_count}");
+                    return Err(ParseError::at(line, input, light_str, expected));
+                }
+                button.push(light);
+            }
+            button_vecs.push(button);
+        }
+
+        let mut buttons: Vec<u16> = Vec::new();
+
+        for nums_row in &button_vecs {
+            let mut new_vec = 0;
+            for i in nums_row.iter().rev() {
+                new_vec |= 1 << i;
+            }
+            buttons.push(new_vec);
+        }
+
+        let joltages_inner = MachineRequirements::bracketed(line, input, joltages_str, '{', '}')?;
+        let joltages: Vec<u16> = joltages_inner
+            .split(",")
+            .map(|joltage| parse::number(line, input, joltage))
+            .collect::<ParseResult<_>>()?;
+        if joltages.len() != light_count {
+            let expected = format!("{light_count} joltages, one per light");
+            return Err(ParseError::at(line, input, joltages_str, expected));
+        }
+
+        Ok(MachineRequirements {
+            lights_requirement: lights,
+            wiring_bits: buttons,
+            wiring_vec: button_vecs,
+            joltage_requirements: joltages,
+        })
+    }
+
+    // the text between `open` and `close`, `part` is a slice of `input`
+    fn bracketed<'a>(
+        line: usize,
+        input: &str,
+        part: &'a str,
+        open: char,
+        close: char,
+    ) -> ParseResult<&'a str> {
+        part.strip_prefix(open)
+            .and_then(|inner| inner.strip_suffix(close))
+            .ok_or_else(|| ParseError::at(line, input, part, format!("\"{open}...{close}\"")))
+    }
+
+    // `None` when no combination of buttons lights up the diagram
+    fn solve_requirements(&self) -> Option<u16> {
+        let mut memo: AHashMap<u16, u16> = AHashMap::new();
+        self.requirements_recursive(&mut memo, &self.wiring_bits, 0, 1)
+    }
+
+    fn requirements_recursive(
+        &self,
+        memo: &mut AHashMap<u16, u16>,
+        available_wiring_bits: &[u16],
+        current_btns: u16,
+        cost: u16,
+    ) -> Option<u16> {
+        if let Some(prev_cost) = memo.get_mut(&current_btns) {
+            if *prev_cost <= cost {
+                return None;
+            } else {
+                *prev_cost = cost;
+            }
+        } else {
+            memo.insert(current_btns, cost);
+        }
+
+        let found_answer = available_wiring_bits
+            .iter()
+            .any(|wiring| (*wiring ^ current_btns) == self.lights_requirement);
+
+        if found_answer {
+            Some(cost)
+        } else {
+            available_wiring_bits
+                .iter()
+                .enumerate()
+                .filter_map(|(i, wiring)| {
+                    self.requirements_recursive(
+                        memo,
+                        &available_wiring_bits[i..],
+                        *wiring ^ current_btns,
+                        cost + 1,
+                    )
+                })
+                .min()
+        }
+    }
+
+    // every button press adds to the joltages, so the presses solve `wiring * presses = joltages`.
+    // after elimination only the free buttons have to be tried, the others follow from them.
+    // `None` when no number of presses reaches the joltages.
+    fn solve_joltages(&self) -> Option<u64> {
+        let (equations, pivots) = self.joltage_equations()?;
+        let buttons = self.wiring_vec.len();
+
+        // a button can't be pressed more often than the lowest joltage it adds to
+        let most_presses: Vec<i64> = self
+            .wiring_vec
+            .iter()
+            .map(|wiring| {
+                wiring
+                    .iter()
+                    .map(|&joltage| self.joltage_requirements[joltage as usize] as i64)
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let free: Vec<usize> = (0..buttons).filter(|b| !pivots.contains(b)).collect();
+
+        let mut presses = vec![0; buttons];
+        let mut fewest = None;
+        MachineRequirements::joltages_recursive(
+            &equations,
+            &free,
+            &most_presses,
+            &mut presses,
+            0,
+            &mut fewest,
+        );
+        fewest
+    }
+
+    // one equation per joltage in reduced row echelon form, kept in whole numbers by scaling the
+    // rows instead of dividing them. The last column is the joltage, every pivot is positive and
+    // the pivot columns are zero in all the other equations. `None` when they contradict each other.
+    fn joltage_equations(&self) -> Option<(Vec<Vec<i64>>, Vec<usize>)> {
+        let buttons = self.wiring_vec.len();
+        let mut equations: Vec<Vec<i64>> = self
+            .joltage_requirements
+            .iter()
+            .map(|&joltage| {
+                let mut equation = vec![0; buttons + 1];
+                equation[buttons] = joltage as i64;
+                equation
+            })
+            .collect();
+        for (button, wiring) in self.wiring_vec.iter().enumerate() {
+            for &joltage in wiring {
+                equations[joltage as usize][button] = 1;
+            }
+        }
+
+        let mut pivots = vec![];
+        for button in 0..buttons {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..equations.len()).find(|&row| equations[row][button] != 0)
+            else {
+                continue;
+            };
+            equations.swap(rank, pivot);
+            if equations[rank][button] < 0 {
+                equations[rank]
+                    .iter_mut()
+                    .for_each(|value| *value = -*value);
+            }
+
+            let pivot_equation = equations[rank].clone();
+            for (row, equation) in equations.iter_mut().enumerate() {
+                let factor = equation[button];
+                if row == rank || factor == 0 {
+                    continue;
+                }
+
+                for (value, pivot_value) in equation.iter_mut().zip(&pivot_equation) {
+                    *value = *value * pivot_equation[button] - pivot_value * factor;
+                }
+                let divisor = math::gcd_all(equation.iter().map(|value| value.unsigned_abs()));
+                if divisor > 1 {
+                    equation
+                        .iter_mut()
+                        .for_each(|value| *value /= divisor as i64);
+                }
+            }
+            pivots.push(button);
+        }
+
+        // what is left over after the pivots reads 0 = joltage
+        if equations[pivots.len()..]
+            .iter()
+            .any(|equation| equation[buttons] != 0)
+        {
+            return None;
+        }
+        equations.truncate(pivots.len());
+
+        Some((equations, pivots))
+    }
+
+    // tries every number of presses for the free buttons, the pivot buttons of the equations have
+    // to come out as whole numbers that aren't negative
+    fn joltages_recursive(
+        equations: &[Vec<i64>],
+        free: &[usize],
+        most_presses: &[i64],
+        presses: &mut [i64],
+        pressed: i64,
+        fewest: &mut Option<u64>,
+    ) {
+        if let [button, rest @ ..] = free {
+            for count in 0..=most_presses[*button] {
+                if fewest.is_some_and(|fewest| (pressed + count) as u64 >= fewest) {
+                    break;
+                }
+                presses[*button] = count;
+                MachineRequirements::joltages_recursive(
+                    equations,
+                    rest,
+                    most_presses,
+                    presses,
+                    pressed + count,
+                    fewest,
+                );
+            }
+            presses[*button] = 0;
+            return;
+        }
+
+        let mut total = pressed;
+        for equation in equations {
+            let (joltage, coefficients) = equation.split_last().unwrap();
+            // the pivot buttons stay at 0 presses, so only the free ones count here
+            let remaining = joltage
+                - coefficients
+                    .iter()
+                    .zip(presses.iter())
+                    .map(|(coefficient, count)| coefficient * count)
+                    .sum::<i64>();
+            let pivot = coefficients.iter().find(|&&value| value != 0).unwrap();
+
+            if remaining < 0 || remaining % pivot != 0 {
+                return;
+            }
+            total += remaining / pivot;
+        }
+
+        if fewest.is_none_or(|fewest| (total as u64) < fewest) {
+            *fewest = Some(total as u64);
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<MachineRequirements>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    // a machine that can't be solved leaves the whole sum without an answer
+    fn part_1(requirements: &Self::Input) -> u64 {
+        requirements
+            .iter()
+            .map(|req| req.solve_requirements().map(u64::from))
+            .sum::<Option<u64>>()
+            .unwrap_or(0)
+    }
+
+    fn part_2(requirements: &Self::Input) -> u64 {
+        requirements
+            .iter()
+            .map(|req| req.solve_joltages())
+            .sum::<Option<u64>>()
+            .unwrap_or(0)
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<Vec<MachineRequirements>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(line, machine)| MachineRequirements::new(line, machine))
+        .collect()
+}
@@ -0,0 +1,28 @@
+use aoc_2025_10::Day;
+use aoc_common::{Solution, harness};
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 7);
+}
+
+#[test]
+fn part_2() {
+    harness::example_2::<Day>("example_2.txt", 33);
+}
+
+#[test]
+fn part_2_per_machine() {
+    let example = harness::read_file("example_2.txt").unwrap();
+    for (machine, expected) in example.iter().zip([10, 12, 11]) {
+        let input = Day::parse(std::slice::from_ref(machine)).unwrap();
+        harness::check("example_2.txt", Day::part_2(&input), expected);
+    }
+}
+
+#[test]
+fn unreachable_joltages() {
+    // no button adds to the second joltage
+    let input = Day::parse(&["[.#] (0) {0,3}".to_owned()]).unwrap();
+    harness::check("unreachable", Day::part_2(&input), 0);
+}
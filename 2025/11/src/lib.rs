@@ -0,0 +1,138 @@
+/*
+part 1:
+we have a list of devices and output devices that those devices are connected to
+we need to start with device labeled "you" find every path to the "out" device
+and return the count of paths
+part 2:
+we now know that we need to find paths from "svr" -> "out" that also visits "dac" and "fft" at some point
+
+*/
+use aoc_common::input::Input;
+use aoc_common::{Graph, ParseError, ParseResult, Solution};
+
+type Name = [u8; 3];
+const START: Name = *b"you";
+const OUT: Name = *b"out";
+
+const SVR: Name = *b"svr";
+const FFT: Name = *b"fft";
+const DAC: Name = *b"dac";
+
+struct Device {
+    name: Name,
+    out: Vec<Name>,
+}
+
+impl Device {
+    // "aaa: bbb ccc"
+    fn new(line: usize, input: &[u8]) -> ParseResult<Self> {
+        let split = input
+            .windows(2)
+            .position(|pair| pair == b": ")
+            .ok_or_else(|| ParseError::at_bytes(line, input, &input[input.len()..], "\": \""))?;
+        let name = Device::name(line, input, &input[..split])?;
+        let outputs_vec: Vec<Name> = input[split + 2..]
+            .split(u8::is_ascii_whitespace)
+            .filter(|name_bytes| !name_bytes.is_empty())
+            .map(|name_bytes| Device::name(line, input, name_bytes))
+            .collect::<ParseResult<_>>()?;
+
+        Ok(Device {
+            name,
+            out: outputs_vec,
+        })
+    }
+
+    // every device name is 3 letters long, `name_bytes` is a slice of `input`
+    fn name(line: usize, input: &[u8], name_bytes: &[u8]) -> ParseResult<Name> {
+        name_bytes
+            .as_array()
+            .copied()
+            .ok_or_else(|| ParseError::at_bytes(line, input, name_bytes, "a 3 letter device name"))
+    }
+}
+
+pub struct AllDevices(Graph<Name>);
+
+impl AllDevices {
+    fn new(devices: &[Device]) -> Self {
+        let mut graph = Graph::new();
+        for device in devices {
+            graph.add_node(device.name);
+            for &out in &device.out {
+                graph.add_edge(device.name, out);
+            }
+        }
+        AllDevices(graph)
+    }
+
+    fn find_out_paths(&self) -> u64 {
+        self.count_paths(&START, &OUT, &[])
+    }
+
+    fn find_svr_out_paths(&self) -> u64 {
+        self.count_paths(&SVR, &OUT, &[FFT, DAC])
+    }
+
+    // parsing makes sure the devices only ever connect forward, so every path ends and can be counted
+    fn count_paths(&self, from: &Name, to: &Name, via: &[Name]) -> u64 {
+        let ids: Option<Vec<usize>> = via.iter().map(|name| self.0.id(name)).collect();
+        let (Some(from), Some(to), Some(via)) = (self.0.id(from), self.0.id(to), ids) else {
+            return 0; // some of the devices are not connected to anything
+        };
+
+        self.0
+            .count_paths_via(from, to, &via)
+            .expect("parse_input rejects devices that loop")
+    }
+}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = AllDevices;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        let lines: Vec<&[u8]> = input.iter().map(|line| line.as_bytes()).collect();
+        parse_input(&lines)
+    }
+
+    fn parse_input(input: &Input) -> ParseResult<Self::Input> {
+        parse_input(&input.byte_lines())
+    }
+
+    fn part_1(devices: &Self::Input) -> u64 {
+        devices.find_out_paths()
+    }
+
+    fn part_2(devices: &Self::Input) -> u64 {
+        devices.find_svr_out_paths()
+    }
+}
+
+fn parse_input(input: &[&[u8]]) -> ParseResult<AllDevices> {
+    let devices: Vec<Device> = input
+        .iter()
+        .enumerate()
+        .map(|(line, &device)| Device::new(line, device))
+        .collect::<ParseResult<_>>()?;
+    let all_devices = AllDevices::new(&devices);
+
+    // a device in a loop has outputs, so it has a line of its own
+    if let Err(cycle) = all_devices.0.topological_sort() {
+        let name = all_devices.0.name(cycle[0]);
+        let line = devices
+            .iter()
+            .position(|device| device.name == *name)
+            .unwrap();
+        return Err(ParseError::at_bytes(
+            line,
+            input[line],
+            &input[line][..name.len()],
+            "a device whose outputs don't lead back to it",
+        ));
+    }
+
+    Ok(all_devices)
+}
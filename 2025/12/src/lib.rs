@@ -0,0 +1,344 @@
+/*
+part 1:
+we have 6 types 3x3 pieces and we have to pack a number of them into an area, we have to find if we can pack the required amount of them in to the area
+*/
+
+#![allow(clippy::ptr_arg)]
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_common::{input, parse};
+use ndarray::Array2;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Object {
+    squares: Array2<bool>,
+}
+
+impl Object {
+    fn n_squares(&self) -> u16 {
+        self.squares.iter().filter(|square| **square).count() as u16
+    }
+
+    // the squares of every distinct rotation and reflection, as offsets from the first square in
+    // reading order
+    fn orientations(&self) -> Vec<Vec<(i32, i32)>> {
+        let mut squares: Vec<(i32, i32)> = self
+            .squares
+            .indexed_iter()
+            .filter(|(_, square)| **square)
+            .map(|((row, column), _)| (row as i32, column as i32))
+            .collect();
+        if squares.is_empty() {
+            return vec![];
+        }
+
+        let mut orientations = vec![];
+        for _ in 0..2 {
+            for _ in 0..4 {
+                squares = squares
+                    .iter()
+                    .map(|&(row, column)| (column, 2 - row))
+                    .collect();
+
+                let mut oriented = squares.clone();
+                oriented.sort();
+                let (first_row, first_column) = oriented[0];
+                let oriented: Vec<(i32, i32)> = oriented
+                    .iter()
+                    .map(|&(row, column)| (row - first_row, column - first_column))
+                    .collect();
+
+                if !orientations.contains(&oriented) {
+                    orientations.push(oriented);
+                }
+            }
+            squares = squares
+                .iter()
+                .map(|&(row, column)| (row, 2 - column))
+                .collect();
+        }
+
+        orientations
+    }
+}
+
+/// The objects packed into a box, every object gets a letter and the empty squares are '.'
+pub struct Packing {
+    width: usize,
+    squares: Vec<char>,
+}
+
+impl fmt::Display for Packing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.squares.chunks(self.width).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+// backtracks over the squares in reading order, the first undecided square either gets the first
+// square of an object or is left empty while there is room to spare
+struct Packer {
+    width: usize,
+    height: usize,
+    orientations: Vec<Vec<Vec<(i32, i32)>>>,
+    remaining: Vec<u8>,
+    squares: Vec<Option<char>>,
+    gaps_left: usize,
+    placed: usize,
+}
+
+impl Packer {
+    fn search(&mut self, from: usize) -> bool {
+        if self.remaining.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let Some(start) = (from..self.squares.len()).find(|&i| self.squares[i].is_none()) else {
+            return false;
+        };
+        let (row, column) = ((start / self.width) as i32, (start % self.width) as i32);
+
+        for shape in 0..self.orientations.len() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+
+            for orientation in 0..self.orientations[shape].len() {
+                let covered: Option<Vec<usize>> = self.orientations[shape][orientation]
+                    .iter()
+                    .map(|&(row_offset, column_offset)| {
+                        let (row, column) = (row + row_offset, column + column_offset);
+                        if !(0..self.height as i32).contains(&row)
+                            || !(0..self.width as i32).contains(&column)
+                        {
+                            return None;
+                        }
+                        let i = row as usize * self.width + column as usize;
+                        self.squares[i].is_none().then_some(i)
+                    })
+                    .collect();
+                let Some(covered) = covered else {
+                    continue;
+                };
+
+                let letter = (b'A' + (self.placed % 26) as u8) as char;
+                for &i in &covered {
+                    self.squares[i] = Some(letter);
+                }
+                self.remaining[shape] -= 1;
+                self.placed += 1;
+
+                if self.search(start + 1) {
+                    return true;
+                }
+
+                for &i in &covered {
+                    self.squares[i] = None;
+                }
+                self.remaining[shape] += 1;
+                self.placed -= 1;
+            }
+        }
+
+        if self.gaps_left > 0 {
+            self.squares[start] = Some('.');
+            self.gaps_left -= 1;
+            if self.search(start + 1) {
+                return true;
+            }
+            self.squares[start] = None;
+            self.gaps_left += 1;
+        }
+
+        false
+    }
+}
+
+pub struct ObjectBox {
+    area_size: (u8, u8),
+    n_objects: [u8; 6],
+}
+
+impl ObjectBox {
+    fn new(line: usize, input: &str) -> ParseResult<Self> {
+        let (shape, counts_str) = parse::split_once(line, input, input, ": ")?;
+        let (x_str, y_str) = parse::split_once(line, input, shape, "x")?;
+        let x: u8 = parse::number(line, input, x_str)?;
+        let y: u8 = parse::number(line, input, y_str)?;
+
+        let counts_vec: Vec<u8> = parse::numbers(line, input, counts_str)?;
+        let object_counts: [u8; 6] = counts_vec.try_into().map_err(|_| {
+            ParseError::at(line, input, counts_str, "a count for each of the 6 shapes")
+        })?;
+
+        Ok(ObjectBox {
+            area_size: (x, y),
+            n_objects: object_counts,
+        })
+    }
+
+    pub fn fits(&self, objects: &[Object; 6]) -> bool {
+        self.trivial_check_fit(objects)
+            .unwrap_or_else(|| self.pack(objects).is_some())
+    }
+
+    // whether the objects obviously fit or obviously don't, `None` when only packing them tells
+    fn trivial_check_fit(&self, objects: &[Object; 6]) -> Option<bool> {
+        // every object fits in its own 3x3 square
+        let squares_3x3 = (self.area_size.0 / 3) as u16 * (self.area_size.1 / 3) as u16;
+        let n_objects: u16 = self.n_objects.iter().map(|&count| count as u16).sum();
+        if n_objects <= squares_3x3 {
+            return Some(true);
+        }
+
+        let total_size = self.area_size.0 as u16 * self.area_size.1 as u16;
+        let max_packing: u16 = self
+            .n_objects
+            .iter()
+            .zip(objects.iter())
+            .map(|(count, obj)| obj.n_squares() * *count as u16)
+            .sum();
+
+        if max_packing > total_size {
+            return Some(false);
+        }
+
+        None
+    }
+
+    /// A way to pack every object into the box, if there is one
+    pub fn pack(&self, objects: &[Object; 6]) -> Option<Packing> {
+        let (width, height) = (self.area_size.0 as usize, self.area_size.1 as usize);
+        // the objects are placed along the short side, so a bad choice shows up soon, in a box
+        // turned on its side when it's wider than tall
+        let turned = width > height;
+        let (packer_width, packer_height) = if turned {
+            (height, width)
+        } else {
+            (width, height)
+        };
+
+        let object_squares: usize = self
+            .n_objects
+            .iter()
+            .zip(objects.iter())
+            .map(|(&count, obj)| obj.n_squares() as usize * count as usize)
+            .sum();
+        let orientations: Vec<_> = objects.iter().map(Object::orientations).collect();
+
+        let mut packer = Packer {
+            width: packer_width,
+            height: packer_height,
+            // objects without squares don't need a place
+            remaining: self
+                .n_objects
+                .iter()
+                .zip(&orientations)
+                .map(|(&count, orientations)| if orientations.is_empty() { 0 } else { count })
+                .collect(),
+            orientations,
+            squares: vec![None; width * height],
+            gaps_left: (width * height).checked_sub(object_squares)?,
+            placed: 0,
+        };
+
+        packer.search(0).then(|| Packing {
+            width,
+            squares: (0..width * height)
+                .map(|i| {
+                    let (row, column) = (i / width, i % width);
+                    let i = if turned { column * height + row } else { i };
+                    packer.squares[i].unwrap_or('.')
+                })
+                .collect(),
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ([Object; 6], Vec<ObjectBox>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PLACEHOLDERS: &'static [u8] = &[2];
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1((objects, boxes): &Self::Input) -> u64 {
+        boxes.iter().filter(|b| b.fits(objects)).count() as u64
+    }
+
+    fn part_2(_input: &Self::Input) -> u64 {
+        0
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<([Object; 6], Vec<ObjectBox>)> {
+    // 6 shapes, each a "0:" header and 3 rows, followed by the boxes
+    let blocks = input::blocks(input);
+    if blocks.len() != 7 {
+        return Err(ParseError::new(
+            input.len(),
+            0,
+            "6 shapes and a list of boxes separated by empty lines",
+            format!("{} blocks", blocks.len()),
+        ));
+    }
+
+    let mut object_vec: Vec<Object> = Vec::new();
+    for shape in &blocks[..6] {
+        let start = shape.start;
+        let header = parse::line(input, start)?;
+        if !header.ends_with(':') {
+            return Err(ParseError::line(
+                start,
+                header,
+                "a shape header like \"0:\"",
+            ));
+        }
+
+        let mut flat_vec: Vec<bool> = Vec::new();
+        if shape.lines.len() != 4 {
+            let last = start + shape.lines.len() - 1;
+            return Err(ParseError::line(last, &input[last], "a shape 3 rows tall"));
+        }
+
+        for line in start + 1..start + 4 {
+            let row = parse::line(input, line)?;
+            if row.len() != 3 {
+                return Err(ParseError::line(line, row, "a row of 3 squares"));
+            }
+
+            for (column, c) in row.char_indices() {
+                match c {
+                    '#' => flat_vec.push(true),
+                    '.' => flat_vec.push(false),
+                    _ => return Err(parse::unexpected_char(line, column, c, "'#' or '.'")),
+                }
+            }
+        }
+
+        object_vec.push(Object {
+            squares: Array2::from_shape_vec((3, 3), flat_vec).unwrap(),
+        });
+    }
+    let object_array: [Object; 6] = object_vec.try_into().unwrap();
+
+    let boxes = blocks[6];
+    let objectbox_vec: Vec<ObjectBox> = boxes
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, object_box)| ObjectBox::new(boxes.start + i, object_box))
+        .collect::<ParseResult<_>>()?;
+
+    Ok((object_array, objectbox_vec))
+}
@@ -0,0 +1,23 @@
+use aoc_2025_12::Day;
+use aoc_common::harness;
+
+#[test]
+fn part_1() {
+    harness::example_1::<Day>("example_1.txt", 2);
+}
+
+#[test]
+fn packing() {
+    let (objects, boxes) = harness::parse_file::<Day>("example_1.txt");
+
+    // the two objects of shape 4 take up 14 of the 16 squares
+    let packing = boxes[0].pack(&objects).unwrap().to_string();
+    assert_eq!(packing.lines().count(), 4);
+    assert_eq!(
+        packing.chars().filter(|c| c.is_ascii_uppercase()).count(),
+        14
+    );
+
+    assert!(boxes[1].pack(&objects).is_some());
+    assert!(boxes[2].pack(&objects).is_none());
+}
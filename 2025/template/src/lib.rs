@@ -0,0 +1,32 @@
+/*
+part 1:
+
+part 2:
+
+*/
+
+#![allow(clippy::ptr_arg)]
+
+use aoc_common::{ParseResult, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PLACEHOLDERS: &'static [u8] = &[1, 2];
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn part_1(_input: &Self::Input) -> u64 {
+        0
+    }
+
+    fn part_2(_input: &Self::Input) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The accepted answers of one year, stored in `<year>/answers.toml` keyed by the zero padded day:
+///
+/// ```toml
+/// [16]
+/// part_1 = "7036"
+/// part_2 = "45"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    New,
+}
+
+impl Answers {
+    /// Loads the answers in `path`, a missing file is a year without any recorded answers
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(err),
+        };
+
+        toml::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&key(day))?;
+        match part {
+            1 => answers.part_1.as_deref(),
+            2 => answers.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str) {
+        let answers = self.0.entry(key(day)).or_default();
+        match part {
+            1 => answers.part_1 = Some(answer.to_owned()),
+            2 => answers.part_2 = Some(answer.to_owned()),
+            _ => unreachable!("only parts 1 and 2 exist"),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Status::New,
+        }
+    }
+}
+
+// zero padded so the days stay in order in the file
+fn key(day: u8) -> String {
+    format!("{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_recorded_answers() {
+        let mut answers: Answers = toml::from_str("[16]\npart_1 = \"7036\"\n").unwrap();
+
+        assert_eq!(answers.check(16, 1, "7036"), Status::Pass);
+        assert_eq!(
+            answers.check(16, 1, "7035"),
+            Status::Fail {
+                expected: "7036".to_owned()
+            }
+        );
+        assert_eq!(answers.check(16, 2, "45"), Status::New);
+
+        answers.record(16, 2, "45");
+        answers.record(3, 1, "161");
+        assert_eq!(answers.check(16, 2, "45"), Status::Pass);
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            "[03]\npart_1 = \"161\"\n\n[16]\npart_1 = \"7036\"\npart_2 = \"45\"\n"
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Median timings of earlier benchmark runs in nanoseconds, keyed by `"<year>/<day>"` and then by
+/// step (`parse`, `part_1` or `part_2`)
+///
+/// Timings only mean something on the machine that made them, so the file lives in `target/`
+/// instead of next to the answers.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, u64>>);
+
+impl Baseline {
+    /// Loads the baseline in `path`, a missing file is an empty baseline
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(err),
+        };
+
+        toml::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, year: u16, day: u8, step: &str) -> Option<Duration> {
+        let nanos = self.0.get(&key(year, day))?.get(step)?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, step: &str, median: Duration) {
+        self.0
+            .entry(key(year, day))
+            .or_default()
+            .insert(step.to_owned(), median.as_nanos() as u64);
+    }
+}
+
+fn key(year: u16, day: u8) -> String {
+    format!("{year}/{day:02}")
+}
+
+/// Relative change of `median` against `baseline`, as shown next to a benchmark
+pub fn change(median: Duration, baseline: Duration) -> String {
+    let ratio = median.as_secs_f64() / baseline.as_secs_f64();
+    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+}
@@ -0,0 +1,583 @@
+mod answers;
+mod baseline;
+mod readme;
+mod registry;
+mod scaffold;
+mod table;
+
+use answers::{Answers, Status};
+use aoc_common::bench::{self, BenchReport, Stats};
+use aoc_common::harness::Report;
+use aoc_common::input::Input;
+use baseline::Baseline;
+use clap::{Parser, Subcommand};
+use registry::Day;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use table::{Format, Row};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions in this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day of a year when the day is left out
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` for stdin, defaults to `<year>/<day>/my_input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Check every day, or every day of one year, against the answers in `<year>/answers.toml`
+    Check {
+        year: Option<u16>,
+        /// Write the answers of parts without a recorded answer to `answers.toml`
+        #[arg(long)]
+        record: bool,
+    },
+    /// Benchmark parsing and each part of one day, comparing the medians with the stored baseline,
+    /// or print a table of the medians of every day of a year when the day is left out
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        /// Only benchmark this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` for stdin, defaults to `<year>/<day>/my_input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Milliseconds to keep sampling each of parse, part 1 and part 2
+        #[arg(long, default_value_t = 1000)]
+        budget_ms: u64,
+        /// Store the medians of this run as the new baseline
+        #[arg(long)]
+        save_baseline: bool,
+        /// Format of the year table
+        #[arg(long, value_enum, default_value_t = Format::Markdown)]
+        format: Format,
+    },
+    /// Regenerate the star tables in `README.md`, a part gets a star once it is not a placeholder and
+    /// its answer is recorded in `answers.toml`
+    Readme {
+        /// Only check that the tables are up to date instead of writing them
+        #[arg(long)]
+        check: bool,
+    },
+    /// Create `<year>/<day>` from the template crate and register it in the workspace and runner
+    New { year: u16, day: u8 },
+    /// List every registered day
+    List,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let days: Vec<&Day> = match day {
+                Some(day) => match registry::find(year, day) {
+                    Some(found) => vec![found],
+                    None => {
+                        eprintln!("{year} day {day} has no registered solution");
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => registry::year(year).collect(),
+            };
+
+            if days.is_empty() {
+                eprintln!("{year} has no registered solutions");
+                return ExitCode::FAILURE;
+            }
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            let mut all_ok = true;
+            for day in days {
+                let path = input.clone().unwrap_or_else(|| default_input(day));
+                all_ok &= run_day(day, &parts, &path);
+            }
+
+            if all_ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Check { year, record } => {
+            let years: Vec<u16> = match year {
+                Some(year) => vec![year],
+                None => registry::years().collect(),
+            };
+
+            let mut summary = Summary::default();
+            for year in years {
+                if !check_year(year, record, &mut summary) {
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            println!(
+                "{} passed, {} failed, {} new, {} skipped",
+                summary.pass, summary.fail, summary.new, summary.skipped
+            );
+
+            if summary.fail == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            input,
+            budget_ms,
+            save_baseline,
+            format,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let config = bench::Config {
+                budget: Duration::from_millis(budget_ms),
+                ..bench::Config::default()
+            };
+
+            let baseline_path = baseline_path();
+            let mut baseline = match Baseline::load(&baseline_path) {
+                Ok(baseline) => baseline,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", baseline_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let all_ok = match day {
+                Some(day) => {
+                    let Some(day) = registry::find(year, day) else {
+                        eprintln!("{year} day {day} has no registered solution");
+                        return ExitCode::FAILURE;
+                    };
+
+                    let path = input.unwrap_or_else(|| default_input(day));
+                    match bench_day(day, &parts, &path, &config, &mut baseline) {
+                        Some((_, lines)) => {
+                            for line in lines {
+                                println!("{line}");
+                            }
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                None => bench_year(year, &parts, &config, &mut baseline, format),
+            };
+
+            if save_baseline {
+                if let Err(err) = baseline.save(&baseline_path) {
+                    eprintln!("could not write {}: {err}", baseline_path.display());
+                    return ExitCode::FAILURE;
+                }
+                eprintln!("saved baseline to {}", baseline_path.display());
+            }
+
+            if all_ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Readme { check } => {
+            if update_readme(check) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::New { year, day } => {
+            if !(1..=readme::calendar_days(year)).contains(&day) {
+                eprintln!("{year} only has days 1 to {}", readme::calendar_days(year));
+                return ExitCode::FAILURE;
+            }
+
+            match scaffold::new_day(workspace_root(), year, day) {
+                Ok(dir) => {
+                    println!("created {}", dir.display());
+                    println!(
+                        "paste the examples into example_1.txt and example_2.txt, then run `cargo test -p aoc-{year}-{day:02}`"
+                    );
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("could not create {year} day {day}: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::List => {
+            for day in registry::DAYS {
+                println!("{} {:02}", day.year, day.day);
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn run_day(day: &Day, parts: &[u8], path: &Path) -> bool {
+    let input = match Input::read(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!(
+                "{} day {:02}: could not read {}: {err}",
+                day.year,
+                day.day,
+                path.display()
+            );
+            return false;
+        }
+    };
+
+    let Some(report) = solve_day(day, parts, path, &input) else {
+        return false;
+    };
+    println!(
+        "{} day {:02} parse: {}µs",
+        day.year,
+        day.day,
+        report.parse.as_micros()
+    );
+    for part in report.parts {
+        println!(
+            "{} day {:02} part {}: {} ({}µs)",
+            day.year,
+            day.day,
+            part.part,
+            part.answer,
+            part.elapsed.as_micros()
+        );
+    }
+    true
+}
+
+fn solve_day(day: &Day, parts: &[u8], path: &Path, input: &Input) -> Option<Report> {
+    match (day.solve)(input, parts) {
+        Ok(report) => Some(report),
+        Err(err) => {
+            eprintln!(
+                "{} day {:02}: could not parse {}: {}",
+                day.year,
+                day.day,
+                path.display(),
+                input.snippet(&err)
+            );
+            None
+        }
+    }
+}
+
+/// Benchmarks every day of `year` with an input and prints a table of the medians to stdout, the
+/// progress goes to stderr so the table can be redirected to a file
+fn bench_year(
+    year: u16,
+    parts: &[u8],
+    config: &bench::Config,
+    baseline: &mut Baseline,
+    format: Format,
+) -> bool {
+    let mut all_ok = true;
+    let mut rows = vec![];
+    for day in registry::year(year) {
+        let path = default_input(day);
+        if !path.exists() {
+            eprintln!("{year} day {:02}: SKIP (no {})", day.day, path.display());
+            continue;
+        }
+
+        match bench_day(day, parts, &path, config, baseline) {
+            Some((report, lines)) => {
+                for line in lines {
+                    eprintln!("{line}");
+                }
+                rows.push(Row::new(day.day, &report));
+            }
+            None => all_ok = false,
+        }
+    }
+
+    if rows.is_empty() {
+        eprintln!("{year} has no days with an input to benchmark");
+        return false;
+    }
+
+    print!("{}", table::render(&rows, format));
+    all_ok
+}
+
+/// Benchmarks one day and updates `baseline` with its medians, the stats are returned as lines that
+/// include the change against the previous baseline
+fn bench_day(
+    day: &Day,
+    parts: &[u8],
+    path: &Path,
+    config: &bench::Config,
+    baseline: &mut Baseline,
+) -> Option<(BenchReport, Vec<String>)> {
+    let input = match Input::read(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!(
+                "{} day {:02}: could not read {}: {err}",
+                day.year,
+                day.day,
+                path.display()
+            );
+            return None;
+        }
+    };
+
+    let report = match (day.bench)(&input, parts, config) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!(
+                "{} day {:02}: could not parse {}: {}",
+                day.year,
+                day.day,
+                path.display(),
+                input.snippet(&err)
+            );
+            return None;
+        }
+    };
+
+    let steps = std::iter::once(("parse".to_owned(), report.parse)).chain(
+        report
+            .parts
+            .iter()
+            .map(|part| (format!("part_{}", part.part), part.stats)),
+    );
+    let mut lines = vec![];
+    for (step, stats) in steps {
+        let previous = baseline.get(day.year, day.day, &step);
+        lines.push(format_stats(day, &step.replace('_', " "), &stats, previous));
+        baseline.set(day.year, day.day, &step, stats.median);
+    }
+
+    Some((report, lines))
+}
+
+fn format_stats(day: &Day, label: &str, stats: &Stats, previous: Option<Duration>) -> String {
+    let change = match previous {
+        Some(previous) => format!(", {} vs baseline", baseline::change(stats.median, previous)),
+        None => String::new(),
+    };
+    format!(
+        "{} day {:02} {label}: median {}, min {}, stddev {} ({} samples{change})",
+        day.year,
+        day.day,
+        bench::format_duration(stats.median),
+        bench::format_duration(stats.min),
+        bench::format_duration(stats.stddev),
+        stats.samples
+    )
+}
+
+#[derive(Default)]
+struct Summary {
+    pass: u32,
+    fail: u32,
+    new: u32,
+    skipped: u32,
+}
+
+/// Returns false when the answers file of `year` could not be read or written
+fn check_year(year: u16, record: bool, summary: &mut Summary) -> bool {
+    let answers_path = answers_path(year);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("could not read {}: {err}", answers_path.display());
+            return false;
+        }
+    };
+
+    let mut recorded = false;
+    for day in registry::year(year) {
+        let path = default_input(day);
+        // inputs are personal and not checked in, so a missing one is skipped rather than failed
+        let Ok(input) = Input::read(&path) else {
+            println!("{year} day {:02}: SKIP (no {})", day.day, path.display());
+            summary.skipped += 1;
+            continue;
+        };
+
+        let Some(report) = solve_day(day, &[1, 2], &path, &input) else {
+            summary.fail += 1;
+            continue;
+        };
+
+        for part in report.parts {
+            let label = format!("{year} day {:02} part {}", day.day, part.part);
+            if day.placeholders.contains(&part.part) {
+                println!("{label}: SKIP (placeholder)");
+                summary.skipped += 1;
+                continue;
+            }
+
+            match answers.check(day.day, part.part, &part.answer) {
+                Status::Pass => {
+                    println!("{label}: PASS");
+                    summary.pass += 1;
+                }
+                Status::Fail { expected } => {
+                    println!("{label}: FAIL (expected {expected}, got {})", part.answer);
+                    summary.fail += 1;
+                }
+                Status::New => {
+                    println!("{label}: NEW ({})", part.answer);
+                    summary.new += 1;
+                    if record {
+                        answers.record(day.day, part.part, &part.answer);
+                        recorded = true;
+                    }
+                }
+            }
+        }
+    }
+
+    if recorded {
+        if let Err(err) = answers.save(&answers_path) {
+            eprintln!("could not write {}: {err}", answers_path.display());
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Returns false when the tables could not be written, or are out of date with `check`
+fn update_readme(check: bool) -> bool {
+    let years: Vec<u16> = registry::years().collect();
+    let mut stars = BTreeSet::new();
+    let mut unrecorded = 0;
+    for &year in &years {
+        let answers = match Answers::load(&answers_path(year)) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("could not read {}: {err}", answers_path(year).display());
+                return false;
+            }
+        };
+
+        for day in registry::year(year) {
+            for part in [1, 2] {
+                if day.placeholders.contains(&part) {
+                    continue;
+                }
+                if answers.get(day.day, part).is_some() {
+                    stars.insert((year, day.day, part));
+                } else {
+                    unrecorded += 1;
+                }
+            }
+        }
+    }
+
+    // the hand-kept tables stay until there is something to generate them from, and a solved part
+    // without an answer would lose the star it already has
+    if stars.is_empty() {
+        println!("no answers are recorded yet, the star tables are kept by hand");
+        return true;
+    }
+    if unrecorded > 0 {
+        eprintln!(
+            "{unrecorded} solved parts have no recorded answer yet, record them with `aoc check --record` before regenerating the star tables"
+        );
+        return false;
+    }
+
+    let readme_path = workspace_root().join("README.md");
+    let readme = match fs::read_to_string(&readme_path) {
+        Ok(readme) => readme,
+        Err(err) => {
+            eprintln!("could not read {}: {err}", readme_path.display());
+            return false;
+        }
+    };
+
+    let tables = readme::star_tables(&years, |year, day, part| stars.contains(&(year, day, part)));
+    let Some(updated) = readme::replace_tables(&readme, &tables) else {
+        eprintln!(
+            "{} has no `{}` and `{}` markers around the star tables",
+            readme_path.display(),
+            readme::BEGIN,
+            readme::END
+        );
+        return false;
+    };
+
+    if updated == readme {
+        println!("star tables are up to date");
+        return true;
+    }
+    if check {
+        eprintln!("star tables are out of date, run `aoc readme` to regenerate them");
+        return false;
+    }
+
+    if let Err(err) = fs::write(&readme_path, updated) {
+        eprintln!("could not write {}: {err}", readme_path.display());
+        return false;
+    }
+    println!("regenerated the star tables in {}", readme_path.display());
+    true
+}
+
+fn answers_path(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string()).join("answers.toml")
+}
+
+fn default_input(day: &Day) -> PathBuf {
+    workspace_root()
+        .join(day.year.to_string())
+        .join(format!("{:02}", day.day))
+        .join("my_input.txt")
+}
+
+/// Timings depend on the machine, so the baseline is kept out of version control
+fn baseline_path() -> PathBuf {
+    workspace_root()
+        .join("target")
+        .join("aoc-bench")
+        .join("baseline.toml")
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner lives inside the workspace")
+}
@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+/// The star tables in `README.md` are generated between these markers
+pub const BEGIN: &str =
+    "<!-- star tables: kept by hand until the answers are recorded, then `aoc readme` -->";
+pub const END: &str = "<!-- end of star tables -->";
+
+/// Days in the calendar of `year`, from 2025 on the calendar only has 12 days
+pub fn calendar_days(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// One section per year, newest first, with a 🦀 for every part `star` returns true for
+pub fn star_tables(years: &[u16], star: impl Fn(u16, u8, u8) -> bool) -> String {
+    let mut years = years.to_vec();
+    years.sort_by(|a, b| b.cmp(a));
+
+    let mut tables = String::new();
+    for year in years {
+        let days = calendar_days(year);
+        // 12 days fit in two rows of 6, 25 days in five rows of 5
+        let columns = if days.is_multiple_of(6) { 6 } else { 5 };
+
+        writeln!(tables, "## {year}\n").unwrap();
+        for (row, first) in (1..=days).step_by(columns).enumerate() {
+            let row_days: Vec<u8> = (first..=days).take(columns).collect();
+
+            let header: String = row_days
+                .iter()
+                .map(|day| format!(" {:<6}|", format!("Day {day}")))
+                .collect();
+            writeln!(tables, "|{header}").unwrap();
+            if row == 0 {
+                writeln!(tables, "|{}", "-------|".repeat(row_days.len())).unwrap();
+            }
+
+            // the same cells as the tables kept by hand, a 🦀 takes up about two columns
+            let stars: String = row_days
+                .iter()
+                .map(|&day| match (star(year, day, 1), star(year, day, 2)) {
+                    (true, true) => " 🦀 🦀|",
+                    (true, false) => " 🦀    |",
+                    (false, true) => "    🦀|",
+                    (false, false) => "       |",
+                })
+                .collect();
+            writeln!(tables, "|{stars}").unwrap();
+        }
+        writeln!(tables).unwrap();
+    }
+
+    tables
+}
+
+/// Replaces everything between the markers in `readme` with `tables`, `None` if the markers are
+/// missing
+pub fn replace_tables(readme: &str, tables: &str) -> Option<String> {
+    let start = readme.find(BEGIN)? + BEGIN.len();
+    let end = start + readme[start..].find(END)?;
+
+    Some(format!(
+        "{}\n\n{tables}{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_star_tables() {
+        let tables = star_tables(&[2025], |_, day, part| day < 12 || part == 1);
+
+        assert_eq!(
+            tables,
+            "## 2025\n\n\
+             | Day 1 | Day 2 | Day 3 | Day 4 | Day 5 | Day 6 |\n\
+             |-------|-------|-------|-------|-------|-------|\n\
+             | 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀 🦀|\n\
+             | Day 7 | Day 8 | Day 9 | Day 10| Day 11| Day 12|\n\
+             | 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀 🦀| 🦀    |\n\n"
+        );
+    }
+
+    #[test]
+    fn replaces_only_the_generated_part() {
+        let readme = format!("# AoC\n{BEGIN}\nold\n{END}\nrest\n");
+
+        assert_eq!(
+            replace_tables(&readme, "new\n").unwrap(),
+            format!("# AoC\n{BEGIN}\n\nnew\n{END}\nrest\n")
+        );
+        assert_eq!(replace_tables("# AoC\n", "new\n"), None);
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::bench::{self, BenchReport};
+use aoc_common::harness::{self, Report};
+use aoc_common::input::Input;
+use aoc_common::{ParseResult, Solution};
+
+/// A single solved day, erased down to the harness entry points of its `Solution`
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&Input, &[u8]) -> ParseResult<Report>,
+    pub bench: fn(&Input, &[u8], &bench::Config) -> ParseResult<BenchReport>,
+    pub placeholders: &'static [u8],
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            solve: harness::solve::<$krate::Day>,
+            bench: bench::bench::<$krate::Day>,
+            placeholders: <$krate::Day as Solution>::PLACEHOLDERS,
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(2023, 1, aoc_2023_01),
+    day!(2023, 2, aoc_2023_02),
+    day!(2023, 3, aoc_2023_03),
+    day!(2023, 4, aoc_2023_04),
+    day!(2023, 5, aoc_2023_05),
+    day!(2023, 6, aoc_2023_06),
+    day!(2023, 7, aoc_2023_07),
+    day!(2023, 8, aoc_2023_08),
+    day!(2023, 9, aoc_2023_09),
+    day!(2023, 10, aoc_2023_10),
+    day!(2023, 11, aoc_2023_11),
+    day!(2023, 12, aoc_2023_12),
+    day!(2023, 13, aoc_2023_13),
+    day!(2023, 14, aoc_2023_14),
+    day!(2023, 15, aoc_2023_15),
+    day!(2023, 16, aoc_2023_16),
+    day!(2023, 17, aoc_2023_17),
+    day!(2023, 18, aoc_2023_18),
+    day!(2023, 19, aoc_2023_19),
+    day!(2023, 20, aoc_2023_20),
+    day!(2023, 21, aoc_2023_21),
+    day!(2023, 22, aoc_2023_22),
+    day!(2023, 23, aoc_2023_23),
+    day!(2024, 1, aoc_2024_01),
+    day!(2024, 2, aoc_2024_02),
+    day!(2024, 3, aoc_2024_03),
+    day!(2024, 4, aoc_2024_04),
+    day!(2024, 5, aoc_2024_05),
+    day!(2024, 6, aoc_2024_06),
+    day!(2024, 7, aoc_2024_07),
+    day!(2024, 8, aoc_2024_08),
+    day!(2024, 9, aoc_2024_09),
+    day!(2024, 10, aoc_2024_10),
+    day!(2024, 11, aoc_2024_11),
+    day!(2024, 12, aoc_2024_12),
+    day!(2024, 13, aoc_2024_13),
+    day!(2024, 14, aoc_2024_14),
+    day!(2024, 15, aoc_2024_15),
+    day!(2024, 16, aoc_2024_16),
+    day!(2024, 17, aoc_2024_17),
+    day!(2024, 18, aoc_2024_18),
+    day!(2024, 19, aoc_2024_19),
+    day!(2024, 20, aoc_2024_20),
+    day!(2024, 21, aoc_2024_21),
+    day!(2024, 22, aoc_2024_22),
+    day!(2024, 23, aoc_2024_23),
+    day!(2024, 24, aoc_2024_24),
+    day!(2024, 25, aoc_2024_25),
+    day!(2025, 1, aoc_2025_01),
+    day!(2025, 2, aoc_2025_02),
+    day!(2025, 3, aoc_2025_03),
+    day!(2025, 4, aoc_2025_04),
+    day!(2025, 5, aoc_2025_05),
+    day!(2025, 6, aoc_2025_06),
+    day!(2025, 7, aoc_2025_07),
+    day!(2025, 8, aoc_2025_08),
+    day!(2025, 9, aoc_2025_09),
+    day!(2025, 10, aoc_2025_10),
+    day!(2025, 11, aoc_2025_11),
+    day!(2025, 12, aoc_2025_12),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+pub fn years() -> impl Iterator<Item = u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|d| d.year).collect();
+    years.dedup();
+    years.into_iter()
+}
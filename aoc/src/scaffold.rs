@@ -0,0 +1,191 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Creates `<year>/<day>` from the template crate of `year`, or of the latest earlier year when
+/// `year` has none yet, and registers it in the workspace and the runner. Returns the new crate's
+/// directory.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<PathBuf> {
+    let day_dir = root.join(year.to_string()).join(format!("{day:02}"));
+    if day_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_dir.display()),
+        ));
+    }
+
+    let (template_year, template_dir) = find_template(root, year)?;
+
+    // edit the manifests before copying, so a day that is already registered is left untouched
+    let workspace_manifest = root.join("Cargo.toml");
+    let workspace = add_workspace_member(&fs::read_to_string(&workspace_manifest)?, year);
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let runner = add_runner_dependency(&fs::read_to_string(&runner_manifest)?, year, day)?;
+    let registry_file = root.join("aoc").join("src").join("registry.rs");
+    let registry = add_registry_entry(&fs::read_to_string(&registry_file)?, year, day)?;
+
+    let renames = [
+        (
+            format!("aoc-{template_year}-template"),
+            format!("aoc-{year}-{day:02}"),
+        ),
+        (
+            format!("aoc_{template_year}_template"),
+            format!("aoc_{year}_{day:02}"),
+        ),
+    ];
+    copy_template(&template_dir, &day_dir, &renames)?;
+    for example in ["example_1.txt", "example_2.txt"] {
+        fs::write(day_dir.join(example), "")?;
+    }
+
+    fs::write(workspace_manifest, workspace)?;
+    fs::write(runner_manifest, runner)?;
+    fs::write(registry_file, registry)?;
+
+    Ok(day_dir)
+}
+
+fn find_template(root: &Path, year: u16) -> io::Result<(u16, PathBuf)> {
+    (2015..=year)
+        .rev()
+        .map(|year| (year, root.join(year.to_string()).join("template")))
+        .find(|(_, dir)| dir.is_dir())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no template crate for {year} or an earlier year"),
+            )
+        })
+}
+
+/// Copies `from` into `to`, renaming the crate in every manifest and source file
+fn copy_template(from: &Path, to: &Path, renames: &[(String, String)]) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_template(&path, &target, renames)?;
+            }
+        } else if entry.file_name() == "my_input.txt" {
+            // inputs are personal, the new day gets its own
+        } else if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("rs" | "toml")
+        ) {
+            let text = renames
+                .iter()
+                .fold(fs::read_to_string(&path)?, |text, (old, new)| {
+                    text.replace(old, new)
+                });
+            fs::write(target, text)?;
+        } else {
+            fs::copy(&path, &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn add_workspace_member(manifest: &str, year: u16) -> String {
+    let member = format!("\"{year}/*\"");
+    if manifest.contains(&member) {
+        return manifest.to_owned();
+    }
+
+    let members = manifest.find("members = [").expect("workspace has members");
+    let end = members
+        + manifest[members..]
+            .find(']')
+            .expect("members list is closed");
+    format!("{}, {member}{}", &manifest[..end], &manifest[end..])
+}
+
+fn add_runner_dependency(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let name = format!("aoc-{year}-{day:02}");
+    let line = format!("{name} = {{ path = \"../{year}/{day:02}\" }}");
+    insert_sorted(manifest, &line, &name, |line| {
+        line.starts_with("aoc-") && !line.starts_with("aoc-common")
+    })
+}
+
+fn add_registry_entry(registry: &str, year: u16, day: u8) -> io::Result<String> {
+    let line = format!("    day!({year}, {day}, aoc_{year}_{day:02}),");
+    // the crate names sort the same way as the days
+    let key = format!("aoc_{year}_{day:02}");
+    insert_sorted(registry, &line, &key, |line| line.starts_with("    day!("))
+}
+
+/// Inserts `new_line` among the consecutive lines matching `is_entry`, keeping them ordered by the
+/// first identifier-like word each of them contains
+fn insert_sorted(
+    text: &str,
+    new_line: &str,
+    key: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> io::Result<String> {
+    let entry_key = |line: &str| {
+        line.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .find(|word| word.starts_with("aoc"))
+            .unwrap_or_default()
+            .replace('-', "_")
+    };
+    let key = key.replace('-', "_");
+
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let Some(&last) = entries.last() else {
+        return Err(io::Error::other(format!("no entries to add {key} next to")));
+    };
+
+    if entries.iter().any(|&i| entry_key(lines[i]) == key) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{key} is already registered"),
+        ));
+    }
+
+    let at = entries
+        .iter()
+        .copied()
+        .find(|&i| entry_key(lines[i]) > key)
+        .unwrap_or(last + 1);
+    lines.insert(at, new_line);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        let registry = "pub static DAYS: &[Day] = &[\n    day!(2024, 9, aoc_2024_09),\n    day!(2024, 11, aoc_2024_11),\n];\n";
+
+        assert_eq!(
+            add_registry_entry(registry, 2024, 10).unwrap(),
+            "pub static DAYS: &[Day] = &[\n    day!(2024, 9, aoc_2024_09),\n    day!(2024, 10, aoc_2024_10),\n    day!(2024, 11, aoc_2024_11),\n];\n"
+        );
+        assert!(add_registry_entry(registry, 2024, 9).is_err());
+
+        let manifest =
+            "aoc-common = { path = \"../common\" }\naoc-2025-12 = { path = \"../2025/12\" }\n";
+        assert_eq!(
+            add_runner_dependency(manifest, 2026, 1).unwrap(),
+            "aoc-common = { path = \"../common\" }\naoc-2025-12 = { path = \"../2025/12\" }\naoc-2026-01 = { path = \"../2026/01\" }\n"
+        );
+
+        assert_eq!(
+            add_workspace_member("members = [\"aoc\", \"2025/*\"]\n", 2026),
+            "members = [\"aoc\", \"2025/*\", \"2026/*\"]\n"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use aoc_common::bench::{self, BenchReport};
+use clap::ValueEnum;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// How many of the slowest days are highlighted in the Markdown table
+const SLOWEST: usize = 3;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Csv,
+}
+
+/// Median timings of one day, parts that were not benchmarked are `None`
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub parse: Duration,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl Row {
+    pub fn new(day: u8, report: &BenchReport) -> Self {
+        let part = |part| {
+            report
+                .parts
+                .iter()
+                .find(|stats| stats.part == part)
+                .map(|stats| stats.stats.median)
+        };
+
+        Row {
+            day,
+            parse: report.parse.median,
+            part_1: part(1),
+            part_2: part(2),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_1.unwrap_or_default() + self.part_2.unwrap_or_default()
+    }
+}
+
+pub fn render(rows: &[Row], format: Format) -> String {
+    match format {
+        Format::Markdown => markdown(rows),
+        Format::Csv => csv(rows),
+    }
+}
+
+/// Markdown table in the style of the star tables in the README, the totals of the slowest days
+/// are in bold
+fn markdown(rows: &[Row]) -> String {
+    let mut by_total: Vec<&Row> = rows.iter().collect();
+    by_total.sort_by_key(|row| std::cmp::Reverse(row.total()));
+    let slowest: Vec<u8> = by_total.iter().take(SLOWEST).map(|row| row.day).collect();
+
+    let cell = |duration: Option<Duration>| duration.map_or("-".to_owned(), bench::format_duration);
+
+    let mut table = String::new();
+    writeln!(table, "| Day | Parse | Part 1 | Part 2 | Total |").unwrap();
+    writeln!(table, "|-----|-------|--------|--------|-------|").unwrap();
+    for row in rows {
+        let total = bench::format_duration(row.total());
+        let total = if slowest.contains(&row.day) {
+            format!("**{total}**")
+        } else {
+            total
+        };
+        writeln!(
+            table,
+            "| {} | {} | {} | {} | {total} |",
+            row.day,
+            bench::format_duration(row.parse),
+            cell(row.part_1),
+            cell(row.part_2)
+        )
+        .unwrap();
+    }
+
+    let sums = Sums::of(rows);
+    writeln!(
+        table,
+        "| Total | {} | {} | {} | {} |",
+        bench::format_duration(sums.parse),
+        cell(sums.part_1),
+        cell(sums.part_2),
+        bench::format_duration(sums.total)
+    )
+    .unwrap();
+
+    table
+}
+
+/// CSV with every timing in nanoseconds so it can be sorted and plotted elsewhere
+fn csv(rows: &[Row]) -> String {
+    let cell =
+        |duration: Option<Duration>| duration.map_or(String::new(), |d| d.as_nanos().to_string());
+
+    let mut table = String::new();
+    writeln!(table, "day,parse_ns,part_1_ns,part_2_ns,total_ns").unwrap();
+    for row in rows {
+        writeln!(
+            table,
+            "{},{},{},{},{}",
+            row.day,
+            row.parse.as_nanos(),
+            cell(row.part_1),
+            cell(row.part_2),
+            row.total().as_nanos()
+        )
+        .unwrap();
+    }
+
+    let sums = Sums::of(rows);
+    writeln!(
+        table,
+        "total,{},{},{},{}",
+        sums.parse.as_nanos(),
+        cell(sums.part_1),
+        cell(sums.part_2),
+        sums.total.as_nanos()
+    )
+    .unwrap();
+
+    table
+}
+
+struct Sums {
+    parse: Duration,
+    part_1: Option<Duration>,
+    part_2: Option<Duration>,
+    total: Duration,
+}
+
+impl Sums {
+    fn of(rows: &[Row]) -> Self {
+        Sums {
+            parse: rows.iter().map(|row| row.parse).sum(),
+            part_1: rows
+                .iter()
+                .filter_map(|row| row.part_1)
+                .reduce(|a, b| a + b),
+            part_2: rows
+                .iter()
+                .filter_map(|row| row.part_2)
+                .reduce(|a, b| a + b),
+            total: rows.iter().map(Row::total).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        [
+            (1, 10, 20, 30),
+            (2, 10, 1_000, 2_000),
+            (3, 1, 2, 3),
+            (4, 1, 1, 1),
+        ]
+        .map(|(day, parse, part_1, part_2)| Row {
+            day,
+            parse: Duration::from_micros(parse),
+            part_1: Some(Duration::from_micros(part_1)),
+            part_2: (day != 4).then(|| Duration::from_micros(part_2)),
+        })
+        .into()
+    }
+
+    #[test]
+    fn renders_markdown_with_the_slowest_days_in_bold() {
+        assert_eq!(
+            render(&rows(), Format::Markdown),
+            "| Day | Parse | Part 1 | Part 2 | Total |\n\
+             |-----|-------|--------|--------|-------|\n\
+             | 1 | 10.00µs | 20.00µs | 30.00µs | **60.00µs** |\n\
+             | 2 | 10.00µs | 1.00ms | 2.00ms | **3.01ms** |\n\
+             | 3 | 1.00µs | 2.00µs | 3.00µs | **6.00µs** |\n\
+             | 4 | 1.00µs | 1.00µs | - | 2.00µs |\n\
+             | Total | 22.00µs | 1.02ms | 2.03ms | 3.08ms |\n"
+        );
+    }
+
+    #[test]
+    fn renders_csv_in_nanoseconds() {
+        let csv = render(&rows(), Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "day,parse_ns,part_1_ns,part_2_ns,total_ns");
+        assert_eq!(lines[4], "4,1000,1000,,2000");
+        assert_eq!(lines[5], "total,22000,1023000,2033000,3078000");
+    }
+}
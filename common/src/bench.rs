@@ -0,0 +1,151 @@
+use crate::input::Input;
+use crate::{ParseResult, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long to keep sampling a single step
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Sampling stops once this much time has been spent, as long as `min_samples` were taken
+    pub budget: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            budget: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 10_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (n.max(2) - 1) as f64;
+
+        Stats {
+            median,
+            min: samples[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            samples: n,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartStats {
+    pub part: u8,
+    pub stats: Stats,
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+    pub parse: Stats,
+    pub parts: Vec<PartStats>,
+}
+
+/// Runs `f` repeatedly, after one untimed warm up run, until the budget in `config` is spent
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    black_box(f());
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < config.max_samples
+        && (samples.len() < config.min_samples || start.elapsed() < config.budget)
+    {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+
+    Stats::from_samples(samples)
+}
+
+/// Benchmarks parsing `input` and each of the requested parts separately
+pub fn bench<S: Solution>(
+    input: &Input,
+    parts: &[u8],
+    config: &Config,
+) -> ParseResult<BenchReport> {
+    let parsed = S::parse_input(input)?;
+    let parse = measure(config, || S::parse_input(black_box(input)));
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let stats = match part {
+                1 => measure(config, || S::part_1(black_box(&parsed))),
+                2 => measure(config, || S::part_2(black_box(&parsed))),
+                _ => unreachable!("only parts 1 and 2 exist"),
+            };
+            PartStats { part, stats }
+        })
+        .collect();
+
+    Ok(BenchReport { parse, parts })
+}
+
+/// Formats `duration` with the unit that keeps it between 1 and 1000
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.samples, 4);
+        // sample standard deviation of 1, 2, 3 and 4
+        assert!((stats.stddev.as_secs_f64() - 0.001291).abs() < 1e-6);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.50µs");
+        assert_eq!(format_duration(Duration::from_micros(2_346)), "2.35ms");
+        assert_eq!(format_duration(Duration::from_millis(1_200)), "1.20s");
+    }
+}
@@ -0,0 +1,96 @@
+/// Where the states of an iterated function start repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first state that is part of the cycle
+    pub tail: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.period
+        }
+    }
+}
+
+/// Brent's algorithm on the states from repeatedly calling `step` on `start`. Only a couple of
+/// states are kept around, but `step` runs around three times per state before the cycle ends.
+pub fn find_cycle<S: Clone + PartialEq>(start: &S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_cycle_by_key(start, step, S::clone)
+}
+
+/// Like `find_cycle`, comparing the states by the fingerprint from `key`, which must be equal only
+/// for equal states
+pub fn find_cycle_by_key<S: Clone, K: PartialEq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // find the period by letting the hare run ahead of a tortoise that teleports to it on every
+    // power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(start);
+    let mut hare = step(start);
+
+    while tortoise != key(&hare) {
+        if power == period {
+            tortoise = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare a period ahead, both meet on the first state of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start.clone(), |state, _| step(&state));
+    let mut tail = 0;
+
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, period }
+}
+
+/// The state after calling `step` `n` times on `start`, skipping the repeating cycles
+pub fn state_after<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = find_cycle(start, &mut step);
+    (0..cycle.reduce(n)).fold(start.clone(), |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tail_and_period() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2
+        let step = |&s: &u32| if s == 5 { 2 } else { s + 1 };
+
+        let cycle = find_cycle(&0, step);
+        assert_eq!(cycle, Cycle { tail: 2, period: 4 });
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(6), 2);
+        assert_eq!(cycle.reduce(1_000_000_001), 5);
+
+        assert_eq!(state_after(&0, step, 1_000_000_002), 2);
+        assert_eq!(find_cycle(&7, |&s| s), Cycle { tail: 0, period: 1 });
+        assert_eq!(
+            find_cycle_by_key(
+                &(0, 0),
+                |&(s, _)| ((s + 1) % 3, (s + 1) % 3 * 2),
+                |&(s, _)| s
+            ),
+            Cycle { tail: 0, period: 3 }
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A directed graph, the node names are interned to ids that index the nodes in the order they
+/// were added
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    names: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+    edge_set: HashSet<(usize, usize)>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            edge_set: HashSet::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of the node called `name`, which is added when it doesn't exist yet
+    pub fn add_node(&mut self, name: N) -> usize {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.ids.insert(name.clone(), id);
+        self.names.push(name);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// An edge from `from` to `to`, the edges of a node keep the order they were added in and an
+    /// edge that already exists is not added again
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        if self.edge_set.insert((from, to)) {
+            self.edges[from].push(to);
+        }
+    }
+
+    /// An edge from `from` to `to` even when there already is one, for nodes whose edges are an
+    /// ordered list of choices that can repeat
+    pub fn push_edge(&mut self, from: N, to: N) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push(to);
+        self.edge_set.insert((from, to));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    pub fn id<Q>(&self, name: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &N {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// The nodes `id` has an edge to
+    pub fn neighbours(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.edge_set.contains(&(from, to))
+    }
+
+    /// Every node ordered so that the edges only point forwards, or a strongly connected component
+    /// that loops when there is no such order
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for &to in self.edges.iter().flatten() {
+            incoming[to] += 1;
+        }
+
+        let mut queue: VecDeque<usize> = self.nodes().filter(|&id| incoming[id] == 0).collect();
+        let mut order = vec![];
+
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for &next in &self.edges[id] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        let cycle = self
+            .strongly_connected_components()
+            .into_iter()
+            .find(|component| component.len() > 1 || self.has_edge(component[0], component[0]))
+            .unwrap();
+        Err(cycle)
+    }
+
+    /// Tarjan's algorithm, the components come out in reverse topological order
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut tarjan = Tarjan {
+            graph: &self.edges,
+            index: vec![None; self.len()],
+            low: vec![0; self.len()],
+            stack: vec![],
+            on_stack: vec![false; self.len()],
+            next_index: 0,
+            components: vec![],
+        };
+
+        for id in self.nodes() {
+            if tarjan.index[id].is_none() {
+                tarjan.visit(id);
+            }
+        }
+
+        tarjan.components
+    }
+
+    /// The number of paths from `from` to `to`, or a component that loops when the graph has a
+    /// cycle and the paths can't be counted
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, Vec<usize>> {
+        let order = self.topological_sort()?;
+        Ok(self.paths_in_order(&order, from, to))
+    }
+
+    /// The number of paths from `from` to `to` that go through every one of the `waypoints`, or a
+    /// component that loops when the graph has a cycle
+    pub fn count_paths_via(
+        &self,
+        from: usize,
+        to: usize,
+        waypoints: &[usize],
+    ) -> Result<u64, Vec<usize>> {
+        let order = self.topological_sort()?;
+        let mut position = vec![0; self.len()];
+        for (i, &id) in order.iter().enumerate() {
+            position[id] = i;
+        }
+
+        // without cycles the waypoints can only be passed in the topological order
+        let mut stops = waypoints.to_vec();
+        stops.sort_by_key(|&id| position[id]);
+        stops.insert(0, from);
+        stops.push(to);
+
+        Ok(stops
+            .windows(2)
+            .map(|leg| self.paths_in_order(&order, leg[0], leg[1]))
+            .product())
+    }
+
+    // counts the paths by passing them forward along a topological `order`
+    fn paths_in_order(&self, order: &[usize], from: usize, to: usize) -> u64 {
+        let mut paths = vec![0; self.len()];
+        paths[from] = 1;
+
+        for &id in order {
+            if paths[id] == 0 {
+                continue;
+            }
+            for &next in &self.edges[id] {
+                paths[next] += paths[id];
+            }
+        }
+
+        paths[to]
+    }
+
+    /// The largest set of nodes that all have edges to each other, found with Bron-Kerbosch. The
+    /// edges have to go both ways.
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut best = vec![];
+        self.bron_kerbosch(&mut vec![], self.nodes().collect(), vec![], &mut best);
+        best
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            return;
+        }
+
+        if clique.len() + candidates.len() <= best.len() {
+            return; // can't get larger than the best one anymore
+        }
+
+        // the neighbours of the pivot are found through the pivot or one of its other neighbours
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|&id| self.edges[id].len())
+            .unwrap();
+
+        for id in candidates.clone() {
+            if self.has_edge(pivot, id) {
+                continue;
+            }
+
+            let connected = |nodes: &[usize]| -> Vec<usize> {
+                nodes
+                    .iter()
+                    .copied()
+                    .filter(|&other| self.has_edge(id, other))
+                    .collect()
+            };
+
+            clique.push(id);
+            self.bron_kerbosch(clique, connected(&candidates), connected(&excluded), best);
+            clique.pop();
+
+            candidates.retain(|&other| other != id);
+            excluded.push(id);
+        }
+    }
+}
+
+struct Tarjan<'a> {
+    graph: &'a [Vec<usize>],
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    next_index: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    /// The depth first search from `root`, with its own stack of the nodes being visited and the
+    /// position in their edges so long paths can't overflow the call stack
+    fn visit(&mut self, root: usize) {
+        self.enter(root);
+        let mut visiting = vec![(root, 0)];
+
+        while let Some((id, edge)) = visiting.pop() {
+            if let Some(&next) = self.graph[id].get(edge) {
+                visiting.push((id, edge + 1));
+                match self.index[next] {
+                    None => {
+                        self.enter(next);
+                        visiting.push((next, 0));
+                    }
+                    Some(index) if self.on_stack[next] => self.low[id] = self.low[id].min(index),
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // every edge is done, so the node hands its low link back to the one that found it
+            if let Some(&(parent, _)) = visiting.last() {
+                self.low[parent] = self.low[parent].min(self.low[id]);
+            }
+
+            // the root of a component pops everything above it off the stack
+            if Some(self.low[id]) == self.index[id] {
+                let mut component = vec![];
+                loop {
+                    let member = self.stack.pop().unwrap();
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == id {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn enter(&mut self, id: usize) {
+        self.index[id] = Some(self.next_index);
+        self.low[id] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(id);
+        self.on_stack[id] = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordering_and_components() {
+        let mut graph = Graph::new();
+        graph.add_edge("shirt", "tie");
+        graph.add_edge("tie", "jacket");
+        graph.add_edge("shirt", "belt");
+        graph.add_edge("belt", "jacket");
+
+        let order = graph.topological_sort().unwrap();
+        let names: Vec<_> = order.iter().map(|&id| *graph.name(id)).collect();
+        assert_eq!(names, ["shirt", "tie", "belt", "jacket"]);
+
+        let (shirt, jacket) = (graph.id("shirt").unwrap(), graph.id("jacket").unwrap());
+        assert_eq!(graph.count_paths(shirt, jacket), Ok(2));
+        assert_eq!(
+            graph.count_paths_via(shirt, jacket, &[graph.id("belt").unwrap()]),
+            Ok(1)
+        );
+        assert_eq!(graph.count_paths(jacket, shirt), Ok(0));
+
+        graph.add_edge("jacket", "tie");
+        let mut cycle = graph.topological_sort().unwrap_err();
+        cycle.sort();
+        assert_eq!(cycle, [graph.id("tie").unwrap(), jacket]);
+        assert!(graph.count_paths(shirt, jacket).is_err());
+        assert_eq!(graph.strongly_connected_components().len(), 3);
+    }
+
+    #[test]
+    fn repeated_edges_and_long_paths() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b");
+        graph.add_edge("a", "b");
+        assert_eq!(graph.neighbours(graph.id("a").unwrap()).len(), 1);
+        graph.push_edge("a", "b");
+        assert_eq!(graph.neighbours(graph.id("a").unwrap()).len(), 2);
+
+        // deep enough to overflow the stack if the components were found recursively
+        let mut chain = Graph::new();
+        for i in 0..200_000 {
+            chain.add_edge(i, i + 1);
+        }
+        chain.add_edge(200_000, 0);
+        let components = chain.strongly_connected_components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 200_001);
+    }
+
+    #[test]
+    fn largest_clique() {
+        let mut graph = Graph::new();
+        for (a, b) in [(1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (2, 4), (1, 4)] {
+            graph.add_undirected_edge(a, b);
+        }
+
+        let mut clique: Vec<_> = graph
+            .max_clique()
+            .into_iter()
+            .map(|id| *graph.name(id))
+            .collect();
+        clique.sort();
+        assert_eq!(clique, [1, 2, 3, 4]);
+    }
+}
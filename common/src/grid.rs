@@ -0,0 +1,451 @@
+use crate::{parse, ParseError, ParseResult};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice::{Chunks, ChunksMut};
+
+/// Offsets to the 4 orthogonal neighbours, up, right, down and left
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all 8 neighbours, clockwise from up
+pub const ADJACENT: [(i64, i64); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense rectangular grid stored row by row, positions are `(x, y)` with `y` growing downwards
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid from its cells row by row, panics when they do not fill whole rows of `width`
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses one cell per character, every character has to be in `allowed` and every row as wide
+    /// as the first one
+    pub fn parse(
+        input: &[String],
+        allowed: &str,
+        mut cell: impl FnMut(char) -> T,
+    ) -> ParseResult<Self> {
+        parse::grid_chars(input, allowed)?;
+
+        let width = input.first().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            let found = input.first().map_or("end of input", |_| "end of line");
+            return Err(ParseError::new(0, 0, "a row of cells", found));
+        }
+        let mut cells = Vec::with_capacity(width * input.len());
+        for (line, row) in input.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(ParseError::line(
+                    line,
+                    row,
+                    format!("a row of {width} cells like the first one"),
+                ));
+            }
+
+            cells.extend(row.chars().map(&mut cell));
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height: input.len(),
+        })
+    }
+
+    /// `parse` for days that work on bytes, one cell per byte
+    pub fn parse_bytes(
+        input: &[&[u8]],
+        allowed: &str,
+        mut cell: impl FnMut(u8) -> T,
+    ) -> ParseResult<Self> {
+        let width = input.first().map_or(0, |row| row.len());
+        if width == 0 {
+            let found = input.first().map_or("end of input", |_| "end of line");
+            return Err(ParseError::new(0, 0, "a row of cells", found));
+        }
+        let mut cells = Vec::with_capacity(width * input.len());
+        for (line, &row) in input.iter().enumerate() {
+            if let Some(column) = row.iter().position(|b| !allowed.as_bytes().contains(b)) {
+                return Err(parse::unexpected_char(
+                    line,
+                    column,
+                    char::from(row[column]),
+                    &format!("one of {allowed:?}"),
+                ));
+            }
+
+            if row.len() != width {
+                return Err(ParseError::at_bytes(
+                    line,
+                    row,
+                    row,
+                    format!("a row of {width} cells like the first one"),
+                ));
+            }
+
+            cells.extend(row.iter().map(|&b| cell(b)));
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height: input.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position `(x, y)` if it is inside the grid, for coordinates that can go negative
+    pub fn checked(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let pos = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Moves `pos` by `(dx, dy)`, a `Point2` or a `Dir4`, `None` when that leaves the grid
+    pub fn step(
+        &self,
+        (x, y): (usize, usize),
+        offset: impl Into<(i64, i64)>,
+    ) -> Option<(usize, usize)> {
+        let (dx, dy) = offset.into();
+        self.checked(x as i64 + dx, y as i64 + dy)
+    }
+
+    /// The cell at `(x, y)` on an infinite plane tiled with copies of the grid
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// The positions from `pos` in steps of `(dx, dy)` until the edge, without `pos` itself
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        offset: impl Into<(i64, i64)>,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let offset = offset.into();
+        std::iter::successors(self.step(pos, offset), move |&pos| self.step(pos, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksMut<'_, T> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every diagonal going down and to the right, starting from the top row and the left column
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)> + '_> {
+        let starts = (0..self.height).rev().map(|y| (0, y));
+        let starts = starts.chain((1..self.width).map(|x| (x, 0)));
+        starts.map(|start| std::iter::once(start).chain(self.ray(start, (1, 1))))
+    }
+
+    /// Every diagonal going down and to the left, starting from the top row and the right column
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)> + '_> {
+        let starts = (0..self.width).map(|x| (x, 0));
+        let starts = starts.chain((1..self.height).map(|y| (self.width - 1, y)));
+        starts.map(|start| std::iter::once(start).chain(self.ray(start, (-1, 1))))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every position with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Rows become columns, the cell at `(x, y)` moves to `(y, x)`
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Turns the grid a quarter clockwise, the top row becomes the right column
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Turns the grid a quarter counterclockwise, the top row becomes the left column
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A `width` by `height` grid whose cell at `pos` is the cell at `source(pos)` in this one
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn((usize, usize)) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source((i % width, i / width))].clone())
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains((x, y)), "({x}, {y}) is outside the grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains((x, y)), "({x}, {y}) is outside the grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Prints the rows of a grid of characters, handy with `dbg!` style debugging
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        let input = ["abc", "def"].map(str::to_owned);
+        Grid::parse(&input, "abcdef", |c| c).unwrap()
+    }
+
+    #[test]
+    fn indexing_and_neighbours() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.checked(-1, 0), None);
+        assert_eq!(*grid.get_wrapping(-1, 5), 'f');
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn lines_through_the_grid() {
+        let grid = grid();
+        let text = |line: &mut dyn Iterator<Item = (usize, usize)>| {
+            line.map(|pos| grid[pos]).collect::<String>()
+        };
+
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(text(&mut grid.ray((0, 0), (1, 0))), "bc");
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| text(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| text(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let input = ["ab", "abc"].map(str::to_owned);
+        let err = Grid::parse(&input, "abc", |c| c).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row of 2 cells like the first one, found \"abc\""
+        );
+    }
+
+    #[test]
+    fn parses_bytes_like_chars() {
+        let rows: [&[u8]; 2] = [b"abc", b"def"];
+        assert_eq!(
+            Grid::parse_bytes(&rows, "abcdef", char::from).unwrap(),
+            grid()
+        );
+
+        let rows: [&[u8]; 2] = [b"ab", b"ax"];
+        let err = Grid::parse_bytes(&rows, "ab", |b| b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected one of \"ab\", found 'x'"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_grid() {
+        let err = Grid::parse(&[], "abc", |c| c).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a row of cells, found end of input"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::bench::{self, Stats};
+use crate::input::Input;
+use crate::{ParseResult, Solution};
+use std::env;
+use std::fmt::Debug;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+/// Parses `input` once and solves the requested parts, timing every step
+pub fn solve<S: Solution>(input: &Input, parts: &[u8]) -> ParseResult<Report> {
+    let start = Instant::now();
+    let parsed = S::parse_input(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_1(&parsed).to_string(),
+                2 => S::part_2(&parsed).to_string(),
+                _ => unreachable!("only parts 1 and 2 exist"),
+            };
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report { parse, parts })
+}
+
+/// Solves both parts for the input in `file_name` and prints the answers, or benchmarks parsing and
+/// both parts when the binary was started with `--bench`. Another input can be passed as an
+/// argument, `-` reads it from stdin.
+pub fn run<S: Solution>(file_name: &str) {
+    let file_name = env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--"))
+        .unwrap_or_else(|| file_name.to_owned());
+    let input = Input::read(&file_name).unwrap_or_else(|err| panic!("{file_name}: {err}"));
+
+    if env::args().any(|arg| arg == "--bench") {
+        let report = bench::bench::<S>(&input, &[1, 2], &bench::Config::default())
+            .unwrap_or_else(|err| panic!("{file_name}: {}", input.snippet(&err)));
+
+        print_stats("parse", &report.parse);
+        for part in report.parts {
+            print_stats(&format!("part {}", part.part), &part.stats);
+        }
+        return;
+    }
+
+    let report = solve::<S>(&input, &[1, 2])
+        .unwrap_or_else(|err| panic!("{file_name}: {}", input.snippet(&err)));
+
+    for part in report.parts {
+        println!("solution {}: {}", part.part, part.answer);
+    }
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    println!(
+        "{label}: median {}, min {}, stddev {} ({} samples)",
+        bench::format_duration(stats.median),
+        bench::format_duration(stats.min),
+        bench::format_duration(stats.stddev),
+        stats.samples
+    );
+}
+
+/// Asserts that part 1 of the example in `file_name` gives `expected`
+pub fn example_1<S: Solution>(file_name: &str, expected: S::Answer1)
+where
+    S::Answer1: PartialEq + Debug,
+{
+    check(file_name, S::part_1(&parse_file::<S>(file_name)), expected);
+}
+
+/// Asserts that part 2 of the example in `file_name` gives `expected`
+pub fn example_2<S: Solution>(file_name: &str, expected: S::Answer2)
+where
+    S::Answer2: PartialEq + Debug,
+{
+    check(file_name, S::part_2(&parse_file::<S>(file_name)), expected);
+}
+
+/// Asserts an example answer that needs something other than `Solution::part_1`/`part_2`, such as
+/// a smaller step count than the real input
+pub fn check<T: PartialEq + Debug>(label: &str, answer: T, expected: T) {
+    println!("{label}: {answer:?}");
+    assert_eq!(answer, expected, "wrong answer for {label}");
+}
+
+/// Reads and parses `file_name`, panicking with the parse diagnostic if it is malformed
+pub fn parse_file<S: Solution>(file_name: &str) -> S::Input {
+    let input = Input::read(file_name).expect("File not found");
+    S::parse_input(&input).unwrap_or_else(|err| panic!("{file_name}: {}", input.snippet(&err)))
+}
+
+/// Reads the lines of `file_name`, or of stdin when it is `-`
+pub fn read_file(file_name: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    Input::read(file_name)?
+        .lines()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+}
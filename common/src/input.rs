@@ -0,0 +1,152 @@
+use crate::{ParseError, ParseResult};
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// The raw contents of a puzzle input, read in full from a file or stdin
+///
+/// Nothing is decoded up front, so byte oriented days can work on `as_bytes` directly while
+/// `as_str` and `lines` report invalid UTF-8 instead of silently cutting the input short.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    bytes: Vec<u8>,
+}
+
+impl Input {
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Input {
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Reads the file at `path`, or stdin when `path` is `-`
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if path == Path::new("-") {
+            Input::from_reader(io::stdin().lock())
+        } else {
+            Ok(Input::from_bytes(fs::read(path)?))
+        }
+    }
+
+    pub fn from_reader(mut reader: impl Read) -> io::Result<Self> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+        Ok(Input::from_bytes(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The whole input as text, an error points at the first byte that is not valid UTF-8
+    pub fn as_str(&self) -> ParseResult<&str> {
+        std::str::from_utf8(&self.bytes).map_err(|err| {
+            let valid = &self.bytes[..err.valid_up_to()];
+            let line = valid.iter().filter(|&&b| b == b'\n').count();
+            let column = valid.len() - valid.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+            let invalid = self.bytes[err.valid_up_to()];
+            ParseError::new(line, column, "valid UTF-8", format!("byte {invalid:#04x}"))
+        })
+    }
+
+    /// The lines of the input without their `\n` or `\r\n`, the form `Solution::parse` takes
+    pub fn lines(&self) -> ParseResult<Vec<String>> {
+        Ok(self.as_str()?.lines().map(str::to_owned).collect())
+    }
+
+    /// The lines of the input as bytes, without their `\n` or `\r\n`
+    pub fn byte_lines(&self) -> Vec<&[u8]> {
+        let bytes = self.bytes.strip_suffix(b"\n").unwrap_or(&self.bytes);
+        if bytes.is_empty() {
+            return vec![];
+        }
+
+        bytes
+            .split(|&b| b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .collect()
+    }
+
+    /// `err` followed by the line it points at, when the input is text
+    pub fn snippet(&self, err: &ParseError) -> String {
+        match self.lines() {
+            Ok(lines) => err.snippet(&lines),
+            Err(_) => err.to_string(),
+        }
+    }
+}
+
+/// A run of non-blank lines, `start` is the index of its first line in the whole input so parse
+/// errors inside the block can still point at the right line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub start: usize,
+    pub lines: &'a [String],
+}
+
+/// Splits `lines` into the blocks between blank lines, several blank lines in a row count as one
+pub fn blocks(lines: &[String]) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut start = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            if start < i {
+                blocks.push(Block {
+                    start,
+                    lines: &lines[start..i],
+                });
+            }
+            start = i + 1;
+        }
+    }
+
+    if start < lines.len() {
+        blocks.push(Block {
+            start,
+            lines: &lines[start..],
+        });
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_of_the_same_input() {
+        let input = Input::from_bytes("ab\r\ncd\n\n\nef\n");
+
+        assert_eq!(input.as_str().unwrap(), "ab\r\ncd\n\n\nef\n");
+        assert_eq!(input.byte_lines(), [&b"ab"[..], b"cd", b"", b"", b"ef"]);
+
+        let lines = input.lines().unwrap();
+        assert_eq!(lines, ["ab", "cd", "", "", "ef"]);
+        assert_eq!(
+            blocks(&lines),
+            [
+                Block {
+                    start: 0,
+                    lines: &lines[0..2]
+                },
+                Block {
+                    start: 4,
+                    lines: &lines[4..]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_utf8() {
+        let input = Input::from_bytes(b"ok\nab\xffc\n".to_vec());
+
+        assert_eq!(input.byte_lines()[1], b"ab\xffc");
+        assert_eq!(
+            input.lines().unwrap_err().to_string(),
+            "line 2, column 3: expected valid UTF-8, found byte 0xff"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+pub mod bench;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod harness;
+pub mod input;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod polygon;
+pub mod range_set;
+pub mod search;
+mod solution;
+pub mod union_find;
+
+pub use graph::Graph;
+pub use grid::Grid;
+pub use memo::Memo;
+pub use parse::{ParseError, ParseResult};
+pub use point::{Dir4, Dir8, Point, Point2, Point3};
+pub use range_set::RangeSet;
+pub use solution::Solution;
+pub use union_find::UnionFind;
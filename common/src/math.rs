@@ -0,0 +1,141 @@
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a as u128, b as u128) as u64
+}
+
+/// The least common multiple, 0 when either number is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// The gcd of all the numbers, 0 for none
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// The lcm of all the numbers, 1 for none
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+pub fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, `None` when it doesn't fit in a `u128`
+pub fn checked_lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd_u128(a, b)).checked_mul(b)
+}
+
+/// The lcm of all the numbers, `None` when it doesn't fit in a `u128`
+pub fn checked_lcm_all(numbers: impl IntoIterator<Item = u128>) -> Option<u128> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// `(g, x, y)` with `a * x + b * y = g`, `g` being the gcd of `a` and `b`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+    let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+    (g, y, x - a.div_euclid(b) * y)
+}
+
+/// `base ^ exp` modulo `modulus`
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// The `x` with `a * x = 1` modulo `modulus`, `None` when `a` and `modulus` share a factor
+pub fn mod_inv(a: u64, modulus: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
+
+/// The `(residue, modulus)` of every number that is `residue` modulo `modulus` for all the given
+/// congruences, the moduli don't have to be coprime. `None` when the congruences contradict each
+/// other or the combined modulus doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    let mut combined = (0, 1);
+
+    for (residue, modulus) in congruences {
+        let (r1, m1) = combined;
+        let r2 = residue % modulus;
+        let g = gcd(m1, modulus);
+
+        let diff = r2 as i128 - r1 as i128;
+        if diff % g as i128 != 0 {
+            return None;
+        }
+
+        // r1 + m1 * k = r2 (mod modulus), so k = diff / g * (m1 / g)^-1 (mod modulus / g)
+        let step = modulus / g;
+        let inverse = mod_inv((m1 / g) % step, step).unwrap_or(0);
+        let k =
+            (diff / g as i128).rem_euclid(step as i128) as u128 * inverse as u128 % step as u128;
+
+        let m = m1.checked_mul(step)?;
+        let r = (r1 as u128 + m1 as u128 * k) % m as u128;
+        combined = (r as u64, m);
+    }
+
+    Some(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+
+        assert_eq!(checked_lcm(u64::MAX as u128, 2), Some(u64::MAX as u128 * 2));
+        assert_eq!(checked_lcm(u128::MAX, 2), None);
+        assert_eq!(checked_lcm_all([3, 5, 7]), Some(105));
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(6, 9), None);
+
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, u64::MAX), (1, 2)]), None);
+    }
+}
@@ -0,0 +1,142 @@
+use fxhash::FxHashMap;
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+/// A cache for recursive functions, which hands itself back to the function on every miss so the
+/// recursive calls go through the same cache
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: FxHashMap<K, V>,
+    name: Option<&'static str>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: FxHashMap::default(),
+            name: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A memo that prints how often it was hit and missed when dropped in a debug build
+    pub fn named(name: &'static str) -> Self {
+        let mut memo = Self::default();
+        memo.name = Some(name);
+        memo
+    }
+
+    /// The value cached for `key`, otherwise the value `compute` returns, which gets cached
+    pub fn cached(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// `cached` for a borrowed `key`, which is only made into an owned key when it is missed
+    pub fn cached_ref<Q>(&mut self, key: &Q, compute: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key.to_owned(), value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets the cached values, the hit and miss counts are kept
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if let (true, Some(name)) = (cfg!(debug_assertions), self.name) {
+            eprintln!(
+                "{name} memo: {} hits, {} misses, {} cached",
+                self.hits,
+                self.misses,
+                self.cache.len()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.cached(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn recursive_calls_share_the_cache() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+
+        // every n from 2 to 90 is computed once and then found once more
+        assert_eq!(memo.misses(), 89);
+        assert_eq!(memo.hits(), 87);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(memo.get(&10), Some(&55));
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(fibonacci(&mut memo, 10), 55);
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        assert_eq!(memo.cached_ref("abc", |_| 3), 3);
+        assert_eq!(memo.cached_ref("abc", |_| unreachable!()), 3);
+        assert_eq!((memo.hits(), memo.misses()), (1, 1));
+        assert_eq!(memo.get(&"abc".to_owned()), Some(&3));
+    }
+}
@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A malformed input, pointing at the token that could not be parsed.
+///
+/// `line` and `column` are zero-based, the same indices `enumerate` and `char_indices` hand out,
+/// and are only turned one-based when the error is displayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error for `token`, which has to be a slice of `text` so its column can be worked out
+    pub fn at(line: usize, text: &str, token: &str, expected: impl Into<String>) -> Self {
+        let found = if token.is_empty() {
+            "end of line".to_owned()
+        } else {
+            format!("{token:?}")
+        };
+
+        ParseError::new(
+            line,
+            column_of(text.as_bytes(), token.as_bytes()),
+            expected,
+            found,
+        )
+    }
+
+    /// `at` for days that parse bytes, `token` has to be a slice of `text`
+    pub fn at_bytes(line: usize, text: &[u8], token: &[u8], expected: impl Into<String>) -> Self {
+        let found = if token.is_empty() {
+            "end of line".to_owned()
+        } else {
+            format!("{:?}", String::from_utf8_lossy(token))
+        };
+
+        ParseError::new(line, column_of(text, token), expected, found)
+    }
+
+    /// An error for a whole line that did not have the expected shape
+    pub fn line(line: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError::at(line, text, text, expected)
+    }
+
+    /// The error followed by the offending line with a caret under the column
+    pub fn snippet(&self, input: &[String]) -> String {
+        let Some(text) = input.get(self.line) else {
+            return self.to_string();
+        };
+
+        let number = (self.line + 1).to_string();
+        let padding = " ".repeat(number.len());
+        let caret = " ".repeat(text[..self.column.min(text.len())].chars().count());
+
+        format!("{self}\n{padding} |\n{number} | {text}\n{padding} | {caret}^")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line + 1,
+            self.column + 1,
+            self.expected,
+            self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Byte offset of `token` inside `text`, or the end of `text` when `token` is not a slice of it
+fn column_of(text: &[u8], token: &[u8]) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+
+    if offset <= text.len() {
+        offset
+    } else {
+        text.len()
+    }
+}
+
+/// Parses `token`, a slice of `text`, into a number
+pub fn number<T: FromStr>(line: usize, text: &str, token: &str) -> ParseResult<T> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, text, token, "a number"))
+}
+
+/// Parses every whitespace separated token of `part`, a slice of `text`, into a number
+pub fn numbers<T: FromStr>(line: usize, text: &str, part: &str) -> ParseResult<Vec<T>> {
+    part.split_whitespace()
+        .map(|token| number(line, text, token))
+        .collect()
+}
+
+/// Splits `part`, a slice of `text`, around the first `delimiter`
+pub fn split_once<'a>(
+    line: usize,
+    text: &str,
+    part: &'a str,
+    delimiter: &str,
+) -> ParseResult<(&'a str, &'a str)> {
+    part.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(line, text, &part[part.len()..], format!("{delimiter:?}")))
+}
+
+/// Reads `c`, found at `column`, as a decimal digit
+pub fn digit(line: usize, column: usize, c: char) -> ParseResult<u32> {
+    c.to_digit(10)
+        .ok_or_else(|| unexpected_char(line, column, c, "a digit"))
+}
+
+/// The line at `index`, or an error pointing just past the end of the input
+pub fn line(input: &[String], index: usize) -> ParseResult<&str> {
+    input
+        .get(index)
+        .map(String::as_str)
+        .ok_or_else(|| ParseError::new(index, 0, "another line", "end of input"))
+}
+
+/// Checks that every line of a grid only uses characters from `allowed`
+pub fn grid_chars(input: &[String], allowed: &str) -> ParseResult<()> {
+    for (line, text) in input.iter().enumerate() {
+        if let Some((column, c)) = text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            return Err(unexpected_char(
+                line,
+                column,
+                c,
+                &format!("one of {allowed:?}"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// An error for the character `found` at `column`, for grids of symbols
+pub fn unexpected_char(line: usize, column: usize, found: char, expected: &str) -> ParseError {
+    ParseError::new(line, column, expected, format!("{found:?}"))
+}
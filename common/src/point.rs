@@ -0,0 +1,343 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// A point or offset on an `N` dimensional integer lattice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Self = Point([0; N]);
+
+    pub fn manhattan(&self, other: &Self) -> u64 {
+        (0..N).map(|i| self.0[i].abs_diff(other.0[i])).sum()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> u64 {
+        (0..N)
+            .map(|i| self.0[i].abs_diff(other.0[i]))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Shrinks every coordinate to -1, 0 or 1, the unit step from the origin towards this point
+    pub fn signum(&self) -> Self {
+        Point(self.0.map(i64::signum))
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+}
+
+impl Point<2> {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point([x, y])
+    }
+
+    /// The grid position of this point, `None` when either coordinate is negative
+    pub fn pos(&self) -> Option<(usize, usize)> {
+        Some((self.x().try_into().ok()?, self.y().try_into().ok()?))
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl Point<3> {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point([x, y, z])
+    }
+
+    pub fn z(&self) -> i64 {
+        self.0[2]
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl From<(usize, usize)> for Point<2> {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::<2>::new(x as i64, y as i64)
+    }
+}
+
+impl From<Point<2>> for (i64, i64) {
+    fn from(point: Point<2>) -> Self {
+        (point.x(), point.y())
+    }
+}
+
+impl From<Dir4> for (i64, i64) {
+    fn from(dir: Dir4) -> Self {
+        dir.offset().into()
+    }
+}
+
+impl From<Dir8> for (i64, i64) {
+    fn from(dir: Dir8) -> Self {
+        dir.offset().into()
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, scalar: i64) -> Self {
+        Point(self.0.map(|c| c * scalar))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+/// The 4 orthogonal directions on a grid with `y` growing downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Clockwise from up
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// `^`, `>`, `v` and `<`
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// The 4 orthogonal and 4 diagonal directions on a grid with `y` growing downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Clockwise from up
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir8::Up => Point2::new(0, -1),
+            Dir8::UpRight => Point2::new(1, -1),
+            Dir8::Right => Point2::new(1, 0),
+            Dir8::DownRight => Point2::new(1, 1),
+            Dir8::Down => Point2::new(0, 1),
+            Dir8::DownLeft => Point2::new(-1, 1),
+            Dir8::Left => Point2::new(-1, 0),
+            Dir8::UpLeft => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl Add<Dir4> for Point2 {
+    type Output = Self;
+
+    fn add(self, dir: Dir4) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl Add<Dir8> for Point2 {
+    type Output = Self;
+
+    fn add(self, dir: Dir8) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign<Dir4> for Point2 {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self = *self + dir;
+    }
+}
+
+impl AddAssign<Dir8> for Point2 {
+    fn add_assign(&mut self, dir: Dir8) {
+        *self = *self + dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(3, -4);
+        let b = Point2::new(-1, 2);
+
+        assert_eq!(a + b, Point2::new(2, -2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 3, Point2::new(9, -12));
+        assert_eq!(-a, Point2::new(-3, 4));
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+        assert_eq!(a.pos(), None);
+        assert_eq!(Point2::from((2, 5)).pos(), Some((2, 5)));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c + Point3::new(0, 0, -1), Point3::new(1, 2, 2));
+        assert_eq!(c.manhattan(&Point3::ORIGIN), 6);
+        assert_eq!(c.z(), 3);
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.offset(), -dir.opposite().offset());
+            assert_eq!(Dir8::from(dir).offset(), dir.offset());
+            assert_eq!(Dir4::from_arrow(dir.arrow()), Some(dir));
+        }
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.offset(), -dir.opposite().offset());
+        }
+
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Point2::new(1, 1) + Dir4::Up, Point2::new(1, 0));
+        assert_eq!(Point2::ORIGIN.neighbours8().count(), 8);
+    }
+}
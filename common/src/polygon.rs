@@ -0,0 +1,189 @@
+use crate::math;
+use crate::Point2;
+
+/// Where a point is compared to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+// the polygons are closed, the last vertex connects back to the first
+fn edges(vertices: &[Point2]) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Twice the area with the shoelace formula, positive when the vertices go counterclockwise with
+/// `y` growing upwards, which is clockwise on a grid with `y` growing downwards
+pub fn twice_signed_area(vertices: &[Point2]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+        .sum()
+}
+
+/// The area, rounded down when it ends in a half
+pub fn area(vertices: &[Point2]) -> u64 {
+    twice_signed_area(vertices).unsigned_abs() / 2
+}
+
+/// The number of lattice points on the edges
+pub fn boundary_points(vertices: &[Point2]) -> u64 {
+    edges(vertices)
+        .map(|(a, b)| math::gcd(a.x().abs_diff(b.x()), a.y().abs_diff(b.y())))
+        .sum()
+}
+
+/// The number of lattice points strictly inside, with Pick's theorem `A = I + B / 2 - 1`. A
+/// degenerate polygon, with all its vertices on one line, has nothing inside.
+pub fn interior_points(vertices: &[Point2]) -> u64 {
+    // the boundary walks a flat polygon twice, so it can count more than `2A + 2`
+    (twice_signed_area(vertices).unsigned_abs() + 2).saturating_sub(boundary_points(vertices)) / 2
+}
+
+pub fn locate(vertices: &[Point2], point: Point2) -> Location {
+    let mut inside = false;
+
+    for (a, b) in edges(vertices) {
+        let cross = (b - a).x() * (point - a).y() - (b - a).y() * (point - a).x();
+        let within = point.x() >= a.x().min(b.x())
+            && point.x() <= a.x().max(b.x())
+            && point.y() >= a.y().min(b.y())
+            && point.y() <= a.y().max(b.y());
+        if cross == 0 && within {
+            return Location::Boundary;
+        }
+
+        // a ray going right from the point crosses the edge
+        if (a.y() > point.y()) != (b.y() > point.y()) {
+            let edge_side =
+                (point.x() - a.x()) * (b.y() - a.y()) - (b.x() - a.x()) * (point.y() - a.y());
+            if (edge_side < 0) == (b.y() > a.y()) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Whether the rectangle between the opposite corners `a` and `b` fits in the polygon, its edges
+/// may lie on the boundary. The polygon has to be rectilinear, every edge horizontal or vertical.
+pub fn rect_in_rectilinear(vertices: &[Point2], a: Point2, b: Point2) -> bool {
+    let (min_x, max_x) = (a.x().min(b.x()), a.x().max(b.x()));
+    let (min_y, max_y) = (a.y().min(b.y()), a.y().max(b.y()));
+
+    // everything is doubled so the points between the vertices are lattice points as well
+    let doubled: Vec<Point2> = vertices.iter().map(|&vertex| vertex * 2).collect();
+    let fits = |x: i64, y: i64| locate(&doubled, Point2::new(x, y)) != Location::Outside;
+
+    if min_x == max_x || min_y == max_y {
+        // a line only goes in or out at the coordinates of a vertex, so checking those and the
+        // points halfway between them covers all of it
+        let mut stops: Vec<i64> = if min_y == max_y {
+            vertices.iter().map(|vertex| vertex.x()).collect()
+        } else {
+            vertices.iter().map(|vertex| vertex.y()).collect()
+        };
+        let (start, end) = if min_y == max_y {
+            (min_x, max_x)
+        } else {
+            (min_y, max_y)
+        };
+        stops.retain(|&stop| stop > start && stop < end);
+        stops.extend([start, end]);
+        stops.sort();
+
+        return stops
+            .windows(2)
+            .flat_map(|pair| [pair[0] * 2, pair[0] + pair[1]])
+            .chain([end * 2])
+            .all(|along| {
+                if min_y == max_y {
+                    fits(along, min_y * 2)
+                } else {
+                    fits(min_x * 2, along)
+                }
+            });
+    }
+
+    // no edge may cut through the inside of the rectangle, which then is either all inside or all
+    // outside, so its center decides
+    let cuts = edges(vertices).any(|(start, end)| {
+        let (low_x, high_x) = (start.x().min(end.x()), start.x().max(end.x()));
+        let (low_y, high_y) = (start.y().min(end.y()), start.y().max(end.y()));
+        low_x < max_x && high_x > min_x && low_y < max_y && high_y > min_y
+    });
+
+    !cuts && fits(min_x + max_x, min_y + max_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(i64, i64)]) -> Vec<Point2> {
+        points.iter().map(|&(x, y)| Point2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn areas_and_lattice_points() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(twice_signed_area(&square), 32);
+        assert_eq!(area(&square), 16);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(twice_signed_area(&reversed), -32);
+
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 3)]);
+        assert_eq!(area(&triangle), 4);
+        assert_eq!(boundary_points(&triangle), 9);
+        assert_eq!(interior_points(&triangle), 1);
+
+        let segment = polygon(&[(0, 0), (3, 0)]);
+        assert_eq!(interior_points(&segment), 0);
+        let collinear = polygon(&[(0, 0), (2, 2), (5, 5)]);
+        assert_eq!(interior_points(&collinear), 0);
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        // an L shape, with the notch at the top right
+        let l_shape = polygon(&[(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (0, 4)]);
+
+        assert_eq!(locate(&l_shape, Point2::new(1, 1)), Location::Inside);
+        assert_eq!(locate(&l_shape, Point2::new(3, 3)), Location::Inside);
+        assert_eq!(locate(&l_shape, Point2::new(3, 1)), Location::Outside);
+        assert_eq!(locate(&l_shape, Point2::new(3, 2)), Location::Boundary);
+        assert_eq!(locate(&l_shape, Point2::new(0, 0)), Location::Boundary);
+        assert_eq!(locate(&l_shape, Point2::new(5, 2)), Location::Outside);
+    }
+
+    #[test]
+    fn rectangles_in_polygon() {
+        let l_shape = polygon(&[(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (0, 4)]);
+        let fits = |a: (i64, i64), b: (i64, i64)| {
+            rect_in_rectilinear(&l_shape, Point2::new(a.0, a.1), Point2::new(b.0, b.1))
+        };
+
+        assert!(fits((0, 0), (2, 4)));
+        assert!(fits((0, 2), (4, 4)));
+        assert!(!fits((0, 0), (4, 4)));
+        assert!(!fits((1, 1), (3, 3)));
+
+        assert!(fits((0, 2), (4, 2)));
+        assert!(fits((2, 0), (2, 4)));
+        assert!(!fits((0, 1), (3, 1)));
+        assert!(!fits((3, 0), (3, 4)));
+    }
+}
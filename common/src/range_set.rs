@@ -0,0 +1,211 @@
+use std::iter::Sum;
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as sorted half-open ranges, that never overlap or touch
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // every range that overlaps or touches the new one gets merged into it
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+
+        // only the first and the last overlapping range can stick out of the removed one
+        let mut kept = vec![];
+        if self.ranges[first].start < range.start {
+            kept.push(self.ranges[first].start..range.start);
+        }
+        if range.end < self.ranges[last - 1].end {
+            kept.push(range.end..self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= *value);
+        i < self.ranges.len() && self.ranges[i].start <= *value
+    }
+
+    /// Whether every value of `range` is in the set
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        let i = self.ranges.partition_point(|r| r.start <= range.start);
+        i > 0 && self.ranges[i - 1].end >= range.end
+    }
+
+    /// Whether any value of `range` is in the set
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        self.within(range.clone()).next().is_some()
+    }
+
+    /// The parts of the set that are inside `range`
+    pub fn within(&self, range: Range<T>) -> impl Iterator<Item = Range<T>> + '_ {
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+
+        self.ranges[first..]
+            .iter()
+            .take_while(move |r| r.start < range.end)
+            .map(move |r| r.start.max(range.start)..r.end.min(range.end))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.difference(&self.difference(other))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeSet<T> {
+    /// Moves the values through a table of `(source, destination start)` pieces, every value in a
+    /// source range is shifted so the source starts at the destination. The first piece a value is
+    /// in wins, the values outside every piece stay where they are.
+    pub fn map_ranges(&self, pieces: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut unmapped = self.clone();
+        let mut mapped = RangeSet::new();
+
+        for (source, destination) in pieces {
+            for range in unmapped.within(source.clone()) {
+                let start = range.start - source.start + destination;
+                mapped.insert(start..start + (range.end - range.start));
+            }
+            unmapped.remove(source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum> RangeSet<T> {
+    /// The number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set: RangeSet<u32> = [10..15, 3..5, 12..18, 5..6, 20..20].into_iter().collect();
+        assert_eq!(set.ranges(), [3..6, 10..18]);
+        assert_eq!(set.len(), 11);
+        assert_eq!(set.min(), Some(3));
+
+        set.remove(4..11);
+        assert_eq!(set.ranges(), [3..4, 11..18]);
+        set.remove(0..3);
+        set.remove(12..13);
+        assert_eq!(set.ranges(), [3..4, 11..12, 13..18]);
+        set.insert(0..100);
+        assert_eq!(set.ranges(), [0..100]);
+        set.remove(0..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn queries_and_set_operations() {
+        let a: RangeSet<i64> = [0..10, 20..30].into_iter().collect();
+        let b: RangeSet<i64> = [5..25].into_iter().collect();
+
+        assert!(a.contains(&0) && a.contains(&29));
+        assert!(!a.contains(&10) && !a.contains(&-1));
+        assert!(a.contains_range(&(2..8)) && !a.contains_range(&(8..21)));
+        assert!(a.overlaps(&(8..21)) && !a.overlaps(&(10..20)));
+
+        assert_eq!(a.union(&b).ranges(), [0..30]);
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(a.within(8..22).collect::<Vec<_>>(), [8..10, 20..22]);
+    }
+
+    #[test]
+    fn piecewise_mapping() {
+        // seeds 79..93 through "50 98 2" and "52 50 48" of the 2023 day 5 example
+        let seeds: RangeSet<u64> = [79..93, 98..100].into_iter().collect();
+        let soil = seeds.map_ranges([(98..100, 50), (50..98, 52)]);
+        assert_eq!(soil.ranges(), [50..52, 81..95]);
+
+        let untouched = seeds.map_ranges([(0..10, 100)]);
+        assert_eq!(untouched, seeds);
+    }
+}
@@ -0,0 +1,327 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A cheapest path, starting with one of the starts and ending with a goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+/// Every cheapest path to the goals, as a DAG of the predecessors every state was reached from
+/// at its lowest cost
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    pub cost: u64,
+    explored: Explored<S>,
+    goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// The goals reached at the lowest cost
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&i| &self.explored.states[i])
+    }
+
+    /// The states a cheapest path to `state` can come from, empty for the starts
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        self.explored
+            .index
+            .get(state)
+            .into_iter()
+            .flat_map(|&i| &self.explored.parents[i])
+            .map(|&i| &self.explored.states[i])
+    }
+
+    /// Every state that is on at least one of the cheapest paths
+    pub fn states(&self) -> HashSet<&S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(i) = stack.pop() {
+            if seen.insert(i) {
+                stack.extend(&self.explored.parents[i]);
+            }
+        }
+
+        seen.into_iter().map(|i| &self.explored.states[i]).collect()
+    }
+
+    /// Every cheapest path, the number of them can grow exponentially with the length
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = vec![];
+        for &goal in &self.goals {
+            self.collect_paths(goal, &mut vec![], &mut paths);
+        }
+        paths
+    }
+
+    fn collect_paths(&self, i: usize, suffix: &mut Vec<usize>, paths: &mut Vec<Vec<S>>) {
+        suffix.push(i);
+
+        if self.explored.parents[i].is_empty() {
+            let path = suffix
+                .iter()
+                .rev()
+                .map(|&i| self.explored.states[i].clone());
+            paths.push(path.collect());
+        }
+
+        for &parent in &self.explored.parents[i] {
+            self.collect_paths(parent, suffix, paths);
+        }
+
+        suffix.pop();
+    }
+}
+
+/// The states seen by a search with their lowest costs and the states they were reached from
+#[derive(Debug, Clone)]
+struct Explored<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    costs: Vec<u64>,
+    parents: Vec<Vec<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Explored<S> {
+    /// Records reaching `state` at `cost` from `parent`, `Some(index)` when it is the new lowest
+    /// cost and the state has to be (re)visited
+    fn reach(&mut self, state: S, cost: u64, parent: Option<usize>, all: bool) -> Option<usize> {
+        let Some(&i) = self.index.get(&state) else {
+            let i = self.states.len();
+            self.index.insert(state.clone(), i);
+            self.states.push(state);
+            self.costs.push(cost);
+            self.parents.push(parent.into_iter().collect());
+            return Some(i);
+        };
+
+        if cost < self.costs[i] {
+            self.costs[i] = cost;
+            self.parents[i] = parent.into_iter().collect();
+            Some(i)
+        } else {
+            if all && cost == self.costs[i] {
+                self.parents[i].extend(parent);
+            }
+            None
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Path<S> {
+        let cost = self.costs[i];
+        let mut states = vec![self.states[i].clone()];
+        while let Some(&parent) = self.parents[i].first() {
+            states.push(self.states[parent].clone());
+            i = parent;
+        }
+        states.reverse();
+
+        Path { cost, states }
+    }
+}
+
+/// A* from all `starts` at once, stopping once every goal at the lowest cost is found, or going
+/// through everything reachable when `is_goal` never holds. With `all` set every predecessor at the
+/// lowest cost is kept, otherwise only the first one.
+fn explore<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> (Explored<S>, Vec<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut explored = Explored {
+        states: vec![],
+        index: HashMap::new(),
+        costs: vec![],
+        parents: vec![],
+    };
+    // (estimated total, cost so far, state index), the lowest estimate first
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = explored.reach(start, 0, None, all) {
+            heap.push(Reverse((estimate, 0, i)));
+        }
+    }
+
+    let mut goals = vec![];
+    let mut goal_estimate = None;
+
+    while let Some(Reverse((estimate, cost, i))) = heap.pop() {
+        if cost > explored.costs[i] {
+            continue; // a cheaper way here was found after this one was queued
+        }
+
+        if goal_estimate.is_some_and(|goal| estimate > goal) {
+            break;
+        }
+
+        let state = explored.states[i].clone();
+        if is_goal(&state) {
+            goals.push(i);
+            goal_estimate = Some(estimate);
+            if !all {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let next_estimate = next_cost + heuristic(&next);
+            if let Some(j) = explored.reach(next, next_cost, Some(i), all) {
+                heap.push(Reverse((next_estimate, next_cost, j)));
+            }
+        }
+    }
+
+    (explored, goals)
+}
+
+/// The number of steps from the nearest of `starts` to every reachable state
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// The lowest cost from the nearest of `starts` to every reachable state
+pub fn distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let (explored, _) = explore(starts, successors, |_| 0, |_| false, false);
+    explored.states.into_iter().zip(explored.costs).collect()
+}
+
+/// A cheapest path from any of `starts` to a state where `is_goal` holds
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Like `dijkstra`, guided by a `heuristic` that must never overestimate the remaining cost
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> u64,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let (explored, goals) = explore(starts, successors, heuristic, is_goal, false);
+    goals.first().map(|&goal| explored.path(goal))
+}
+
+/// All the cheapest paths from `starts` to the states where `is_goal` holds
+pub fn all_shortest_paths<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let (explored, goals) = explore(starts, successors, |_| 0, is_goal, true);
+    let cost = explored.costs[*goals.first()?];
+
+    Some(ShortestPaths {
+        cost,
+        explored,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a diamond 0 -> 1 | 2 -> 3 with two equally cheap ways through, and a pricier shortcut 0 -> 3
+    fn diamond(&state: &u8) -> Vec<(u8, u64)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_paths() {
+        let path = dijkstra([0], diamond, |&s| s == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states.len(), 3);
+
+        let guided = astar([0], diamond, |&s| if s == 3 { 0 } else { 1 }, |&s| s == 3);
+        assert_eq!(guided.unwrap().cost, 3);
+
+        assert_eq!(dijkstra([0], diamond, |&s| s == 9), None);
+        assert_eq!(distances([0], diamond)[&3], 3);
+        assert_eq!(
+            bfs([0], |&s| diamond(&s).into_iter().map(|(n, _)| n))[&3],
+            1
+        );
+    }
+
+    #[test]
+    fn every_cheapest_path() {
+        let paths = all_shortest_paths([0], diamond, |&s| s == 3).unwrap();
+        assert_eq!(paths.cost, 3);
+        assert_eq!(paths.goals().collect::<Vec<_>>(), [&3]);
+        assert_eq!(paths.states().len(), 4);
+
+        let mut all = paths.paths();
+        all.sort();
+        assert_eq!(all, [vec![0, 1, 3], vec![0, 2, 3]]);
+
+        let mut before = paths.predecessors(&3).copied().collect::<Vec<_>>();
+        before.sort();
+        assert_eq!(before, [1, 2]);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::input::Input;
+use crate::ParseResult;
+use std::fmt::Display;
+
+/// One day of the calendar.
+///
+/// The input is parsed once into `Input` and both parts are solved from the parsed form, so the
+/// harness can time parsing and solving separately. The answers only need to be printable since
+/// some days answer with a `String` instead of a number. Parsing is the only fallible step: a
+/// malformed input is reported as a `ParseError` pointing at the offending token.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parts that are not solved yet and only return a placeholder, they are left out of the
+    /// recorded answers and the README star tables
+    const PLACEHOLDERS: &'static [u8] = &[];
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input>;
+
+    /// Parses the raw input, the harness goes through this one. By default it is decoded into the
+    /// lines `parse` takes, byte oriented days override it to read `Input::byte_lines` directly.
+    fn parse_input(input: &Input) -> ParseResult<Self::Input> {
+        Self::parse(&input.lines()?)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(input: &Self::Input) -> Self::Answer2;
+}
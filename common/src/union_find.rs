@@ -0,0 +1,108 @@
+/// Disjoint sets of the elements `0..len`, with path compression and union by size
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Every element starts in a component of its own
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The representative of the component `element` is in
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // point everything on the way straight at the root
+        let mut current = element;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Joins the components of `a` and `b`, `false` when they already were the same one
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the component `element` is in
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// The number of separate components
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every component, in no particular order
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| self.parents[i] == i)
+            .map(|i| self.sizes[i])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joining_components() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.components(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.components(), 3);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 4]);
+    }
+}